@@ -0,0 +1,31 @@
+use std::env;
+use std::fs::File;
+use std::io::Write;
+use std::path::Path;
+
+// Must match MAX_SIZE in src/go/mod.rs. The tables are declared with the
+// VIRT_LEN of src/go/mod.rs, so a mismatch doesn't compile.
+const MAX_SIZE: usize = 25;
+// Includes a one stone border on all sides of the board.
+const VIRT_SIZE: usize = MAX_SIZE + 2;
+const VIRT_LEN: usize = VIRT_SIZE * VIRT_SIZE;
+
+// Writes a table with 4 offset neighbours for every vertex of the virtual
+// board.
+fn write_table(f: &mut File, name: &str, offsets: [i32; 4]) {
+  writeln!(f, "pub static {}: [[Vertex; 4]; VIRT_LEN] = [", name).unwrap();
+  for i in 0 .. VIRT_LEN as i32 {
+    writeln!(f, "  [Vertex({}), Vertex({}), Vertex({}), Vertex({})],",
+      i + offsets[0], i + offsets[1], i + offsets[2], i + offsets[3]).unwrap();
+  }
+  writeln!(f, "];").unwrap();
+}
+
+fn main() {
+  let out_dir = env::var("OUT_DIR").unwrap();
+  let mut f = File::create(Path::new(&out_dir).join("neighbours.rs")).unwrap();
+
+  let size = VIRT_SIZE as i32;
+  write_table(&mut f, "NEIGHBOURS", [-1, 1, -size, size]);
+  write_table(&mut f, "DIAG_NEIGHBOURS", [-size - 1, -size + 1, size - 1, size + 1]);
+}
@@ -1,891 +1,6 @@
 use super::vertex::Vertex;
+use super::VIRT_LEN;
 
-// print',\n'.join(["[Vertex(%d - 1), Vertex(%d + 1), Vertex(%d - 21), Vertex(%d + 21)]" % (i, i, i, i) for i in range(21 * 21)])
-pub static NEIGHBOURS: [[Vertex; 4]; 21 * 21] = [
-  [Vertex(0 - 1), Vertex(0 + 1), Vertex(0 - 21), Vertex(0 + 21)],
-  [Vertex(1 - 1), Vertex(1 + 1), Vertex(1 - 21), Vertex(1 + 21)],
-  [Vertex(2 - 1), Vertex(2 + 1), Vertex(2 - 21), Vertex(2 + 21)],
-  [Vertex(3 - 1), Vertex(3 + 1), Vertex(3 - 21), Vertex(3 + 21)],
-  [Vertex(4 - 1), Vertex(4 + 1), Vertex(4 - 21), Vertex(4 + 21)],
-  [Vertex(5 - 1), Vertex(5 + 1), Vertex(5 - 21), Vertex(5 + 21)],
-  [Vertex(6 - 1), Vertex(6 + 1), Vertex(6 - 21), Vertex(6 + 21)],
-  [Vertex(7 - 1), Vertex(7 + 1), Vertex(7 - 21), Vertex(7 + 21)],
-  [Vertex(8 - 1), Vertex(8 + 1), Vertex(8 - 21), Vertex(8 + 21)],
-  [Vertex(9 - 1), Vertex(9 + 1), Vertex(9 - 21), Vertex(9 + 21)],
-  [Vertex(10 - 1), Vertex(10 + 1), Vertex(10 - 21), Vertex(10 + 21)],
-  [Vertex(11 - 1), Vertex(11 + 1), Vertex(11 - 21), Vertex(11 + 21)],
-  [Vertex(12 - 1), Vertex(12 + 1), Vertex(12 - 21), Vertex(12 + 21)],
-  [Vertex(13 - 1), Vertex(13 + 1), Vertex(13 - 21), Vertex(13 + 21)],
-  [Vertex(14 - 1), Vertex(14 + 1), Vertex(14 - 21), Vertex(14 + 21)],
-  [Vertex(15 - 1), Vertex(15 + 1), Vertex(15 - 21), Vertex(15 + 21)],
-  [Vertex(16 - 1), Vertex(16 + 1), Vertex(16 - 21), Vertex(16 + 21)],
-  [Vertex(17 - 1), Vertex(17 + 1), Vertex(17 - 21), Vertex(17 + 21)],
-  [Vertex(18 - 1), Vertex(18 + 1), Vertex(18 - 21), Vertex(18 + 21)],
-  [Vertex(19 - 1), Vertex(19 + 1), Vertex(19 - 21), Vertex(19 + 21)],
-  [Vertex(20 - 1), Vertex(20 + 1), Vertex(20 - 21), Vertex(20 + 21)],
-  [Vertex(21 - 1), Vertex(21 + 1), Vertex(21 - 21), Vertex(21 + 21)],
-  [Vertex(22 - 1), Vertex(22 + 1), Vertex(22 - 21), Vertex(22 + 21)],
-  [Vertex(23 - 1), Vertex(23 + 1), Vertex(23 - 21), Vertex(23 + 21)],
-  [Vertex(24 - 1), Vertex(24 + 1), Vertex(24 - 21), Vertex(24 + 21)],
-  [Vertex(25 - 1), Vertex(25 + 1), Vertex(25 - 21), Vertex(25 + 21)],
-  [Vertex(26 - 1), Vertex(26 + 1), Vertex(26 - 21), Vertex(26 + 21)],
-  [Vertex(27 - 1), Vertex(27 + 1), Vertex(27 - 21), Vertex(27 + 21)],
-  [Vertex(28 - 1), Vertex(28 + 1), Vertex(28 - 21), Vertex(28 + 21)],
-  [Vertex(29 - 1), Vertex(29 + 1), Vertex(29 - 21), Vertex(29 + 21)],
-  [Vertex(30 - 1), Vertex(30 + 1), Vertex(30 - 21), Vertex(30 + 21)],
-  [Vertex(31 - 1), Vertex(31 + 1), Vertex(31 - 21), Vertex(31 + 21)],
-  [Vertex(32 - 1), Vertex(32 + 1), Vertex(32 - 21), Vertex(32 + 21)],
-  [Vertex(33 - 1), Vertex(33 + 1), Vertex(33 - 21), Vertex(33 + 21)],
-  [Vertex(34 - 1), Vertex(34 + 1), Vertex(34 - 21), Vertex(34 + 21)],
-  [Vertex(35 - 1), Vertex(35 + 1), Vertex(35 - 21), Vertex(35 + 21)],
-  [Vertex(36 - 1), Vertex(36 + 1), Vertex(36 - 21), Vertex(36 + 21)],
-  [Vertex(37 - 1), Vertex(37 + 1), Vertex(37 - 21), Vertex(37 + 21)],
-  [Vertex(38 - 1), Vertex(38 + 1), Vertex(38 - 21), Vertex(38 + 21)],
-  [Vertex(39 - 1), Vertex(39 + 1), Vertex(39 - 21), Vertex(39 + 21)],
-  [Vertex(40 - 1), Vertex(40 + 1), Vertex(40 - 21), Vertex(40 + 21)],
-  [Vertex(41 - 1), Vertex(41 + 1), Vertex(41 - 21), Vertex(41 + 21)],
-  [Vertex(42 - 1), Vertex(42 + 1), Vertex(42 - 21), Vertex(42 + 21)],
-  [Vertex(43 - 1), Vertex(43 + 1), Vertex(43 - 21), Vertex(43 + 21)],
-  [Vertex(44 - 1), Vertex(44 + 1), Vertex(44 - 21), Vertex(44 + 21)],
-  [Vertex(45 - 1), Vertex(45 + 1), Vertex(45 - 21), Vertex(45 + 21)],
-  [Vertex(46 - 1), Vertex(46 + 1), Vertex(46 - 21), Vertex(46 + 21)],
-  [Vertex(47 - 1), Vertex(47 + 1), Vertex(47 - 21), Vertex(47 + 21)],
-  [Vertex(48 - 1), Vertex(48 + 1), Vertex(48 - 21), Vertex(48 + 21)],
-  [Vertex(49 - 1), Vertex(49 + 1), Vertex(49 - 21), Vertex(49 + 21)],
-  [Vertex(50 - 1), Vertex(50 + 1), Vertex(50 - 21), Vertex(50 + 21)],
-  [Vertex(51 - 1), Vertex(51 + 1), Vertex(51 - 21), Vertex(51 + 21)],
-  [Vertex(52 - 1), Vertex(52 + 1), Vertex(52 - 21), Vertex(52 + 21)],
-  [Vertex(53 - 1), Vertex(53 + 1), Vertex(53 - 21), Vertex(53 + 21)],
-  [Vertex(54 - 1), Vertex(54 + 1), Vertex(54 - 21), Vertex(54 + 21)],
-  [Vertex(55 - 1), Vertex(55 + 1), Vertex(55 - 21), Vertex(55 + 21)],
-  [Vertex(56 - 1), Vertex(56 + 1), Vertex(56 - 21), Vertex(56 + 21)],
-  [Vertex(57 - 1), Vertex(57 + 1), Vertex(57 - 21), Vertex(57 + 21)],
-  [Vertex(58 - 1), Vertex(58 + 1), Vertex(58 - 21), Vertex(58 + 21)],
-  [Vertex(59 - 1), Vertex(59 + 1), Vertex(59 - 21), Vertex(59 + 21)],
-  [Vertex(60 - 1), Vertex(60 + 1), Vertex(60 - 21), Vertex(60 + 21)],
-  [Vertex(61 - 1), Vertex(61 + 1), Vertex(61 - 21), Vertex(61 + 21)],
-  [Vertex(62 - 1), Vertex(62 + 1), Vertex(62 - 21), Vertex(62 + 21)],
-  [Vertex(63 - 1), Vertex(63 + 1), Vertex(63 - 21), Vertex(63 + 21)],
-  [Vertex(64 - 1), Vertex(64 + 1), Vertex(64 - 21), Vertex(64 + 21)],
-  [Vertex(65 - 1), Vertex(65 + 1), Vertex(65 - 21), Vertex(65 + 21)],
-  [Vertex(66 - 1), Vertex(66 + 1), Vertex(66 - 21), Vertex(66 + 21)],
-  [Vertex(67 - 1), Vertex(67 + 1), Vertex(67 - 21), Vertex(67 + 21)],
-  [Vertex(68 - 1), Vertex(68 + 1), Vertex(68 - 21), Vertex(68 + 21)],
-  [Vertex(69 - 1), Vertex(69 + 1), Vertex(69 - 21), Vertex(69 + 21)],
-  [Vertex(70 - 1), Vertex(70 + 1), Vertex(70 - 21), Vertex(70 + 21)],
-  [Vertex(71 - 1), Vertex(71 + 1), Vertex(71 - 21), Vertex(71 + 21)],
-  [Vertex(72 - 1), Vertex(72 + 1), Vertex(72 - 21), Vertex(72 + 21)],
-  [Vertex(73 - 1), Vertex(73 + 1), Vertex(73 - 21), Vertex(73 + 21)],
-  [Vertex(74 - 1), Vertex(74 + 1), Vertex(74 - 21), Vertex(74 + 21)],
-  [Vertex(75 - 1), Vertex(75 + 1), Vertex(75 - 21), Vertex(75 + 21)],
-  [Vertex(76 - 1), Vertex(76 + 1), Vertex(76 - 21), Vertex(76 + 21)],
-  [Vertex(77 - 1), Vertex(77 + 1), Vertex(77 - 21), Vertex(77 + 21)],
-  [Vertex(78 - 1), Vertex(78 + 1), Vertex(78 - 21), Vertex(78 + 21)],
-  [Vertex(79 - 1), Vertex(79 + 1), Vertex(79 - 21), Vertex(79 + 21)],
-  [Vertex(80 - 1), Vertex(80 + 1), Vertex(80 - 21), Vertex(80 + 21)],
-  [Vertex(81 - 1), Vertex(81 + 1), Vertex(81 - 21), Vertex(81 + 21)],
-  [Vertex(82 - 1), Vertex(82 + 1), Vertex(82 - 21), Vertex(82 + 21)],
-  [Vertex(83 - 1), Vertex(83 + 1), Vertex(83 - 21), Vertex(83 + 21)],
-  [Vertex(84 - 1), Vertex(84 + 1), Vertex(84 - 21), Vertex(84 + 21)],
-  [Vertex(85 - 1), Vertex(85 + 1), Vertex(85 - 21), Vertex(85 + 21)],
-  [Vertex(86 - 1), Vertex(86 + 1), Vertex(86 - 21), Vertex(86 + 21)],
-  [Vertex(87 - 1), Vertex(87 + 1), Vertex(87 - 21), Vertex(87 + 21)],
-  [Vertex(88 - 1), Vertex(88 + 1), Vertex(88 - 21), Vertex(88 + 21)],
-  [Vertex(89 - 1), Vertex(89 + 1), Vertex(89 - 21), Vertex(89 + 21)],
-  [Vertex(90 - 1), Vertex(90 + 1), Vertex(90 - 21), Vertex(90 + 21)],
-  [Vertex(91 - 1), Vertex(91 + 1), Vertex(91 - 21), Vertex(91 + 21)],
-  [Vertex(92 - 1), Vertex(92 + 1), Vertex(92 - 21), Vertex(92 + 21)],
-  [Vertex(93 - 1), Vertex(93 + 1), Vertex(93 - 21), Vertex(93 + 21)],
-  [Vertex(94 - 1), Vertex(94 + 1), Vertex(94 - 21), Vertex(94 + 21)],
-  [Vertex(95 - 1), Vertex(95 + 1), Vertex(95 - 21), Vertex(95 + 21)],
-  [Vertex(96 - 1), Vertex(96 + 1), Vertex(96 - 21), Vertex(96 + 21)],
-  [Vertex(97 - 1), Vertex(97 + 1), Vertex(97 - 21), Vertex(97 + 21)],
-  [Vertex(98 - 1), Vertex(98 + 1), Vertex(98 - 21), Vertex(98 + 21)],
-  [Vertex(99 - 1), Vertex(99 + 1), Vertex(99 - 21), Vertex(99 + 21)],
-  [Vertex(100 - 1), Vertex(100 + 1), Vertex(100 - 21), Vertex(100 + 21)],
-  [Vertex(101 - 1), Vertex(101 + 1), Vertex(101 - 21), Vertex(101 + 21)],
-  [Vertex(102 - 1), Vertex(102 + 1), Vertex(102 - 21), Vertex(102 + 21)],
-  [Vertex(103 - 1), Vertex(103 + 1), Vertex(103 - 21), Vertex(103 + 21)],
-  [Vertex(104 - 1), Vertex(104 + 1), Vertex(104 - 21), Vertex(104 + 21)],
-  [Vertex(105 - 1), Vertex(105 + 1), Vertex(105 - 21), Vertex(105 + 21)],
-  [Vertex(106 - 1), Vertex(106 + 1), Vertex(106 - 21), Vertex(106 + 21)],
-  [Vertex(107 - 1), Vertex(107 + 1), Vertex(107 - 21), Vertex(107 + 21)],
-  [Vertex(108 - 1), Vertex(108 + 1), Vertex(108 - 21), Vertex(108 + 21)],
-  [Vertex(109 - 1), Vertex(109 + 1), Vertex(109 - 21), Vertex(109 + 21)],
-  [Vertex(110 - 1), Vertex(110 + 1), Vertex(110 - 21), Vertex(110 + 21)],
-  [Vertex(111 - 1), Vertex(111 + 1), Vertex(111 - 21), Vertex(111 + 21)],
-  [Vertex(112 - 1), Vertex(112 + 1), Vertex(112 - 21), Vertex(112 + 21)],
-  [Vertex(113 - 1), Vertex(113 + 1), Vertex(113 - 21), Vertex(113 + 21)],
-  [Vertex(114 - 1), Vertex(114 + 1), Vertex(114 - 21), Vertex(114 + 21)],
-  [Vertex(115 - 1), Vertex(115 + 1), Vertex(115 - 21), Vertex(115 + 21)],
-  [Vertex(116 - 1), Vertex(116 + 1), Vertex(116 - 21), Vertex(116 + 21)],
-  [Vertex(117 - 1), Vertex(117 + 1), Vertex(117 - 21), Vertex(117 + 21)],
-  [Vertex(118 - 1), Vertex(118 + 1), Vertex(118 - 21), Vertex(118 + 21)],
-  [Vertex(119 - 1), Vertex(119 + 1), Vertex(119 - 21), Vertex(119 + 21)],
-  [Vertex(120 - 1), Vertex(120 + 1), Vertex(120 - 21), Vertex(120 + 21)],
-  [Vertex(121 - 1), Vertex(121 + 1), Vertex(121 - 21), Vertex(121 + 21)],
-  [Vertex(122 - 1), Vertex(122 + 1), Vertex(122 - 21), Vertex(122 + 21)],
-  [Vertex(123 - 1), Vertex(123 + 1), Vertex(123 - 21), Vertex(123 + 21)],
-  [Vertex(124 - 1), Vertex(124 + 1), Vertex(124 - 21), Vertex(124 + 21)],
-  [Vertex(125 - 1), Vertex(125 + 1), Vertex(125 - 21), Vertex(125 + 21)],
-  [Vertex(126 - 1), Vertex(126 + 1), Vertex(126 - 21), Vertex(126 + 21)],
-  [Vertex(127 - 1), Vertex(127 + 1), Vertex(127 - 21), Vertex(127 + 21)],
-  [Vertex(128 - 1), Vertex(128 + 1), Vertex(128 - 21), Vertex(128 + 21)],
-  [Vertex(129 - 1), Vertex(129 + 1), Vertex(129 - 21), Vertex(129 + 21)],
-  [Vertex(130 - 1), Vertex(130 + 1), Vertex(130 - 21), Vertex(130 + 21)],
-  [Vertex(131 - 1), Vertex(131 + 1), Vertex(131 - 21), Vertex(131 + 21)],
-  [Vertex(132 - 1), Vertex(132 + 1), Vertex(132 - 21), Vertex(132 + 21)],
-  [Vertex(133 - 1), Vertex(133 + 1), Vertex(133 - 21), Vertex(133 + 21)],
-  [Vertex(134 - 1), Vertex(134 + 1), Vertex(134 - 21), Vertex(134 + 21)],
-  [Vertex(135 - 1), Vertex(135 + 1), Vertex(135 - 21), Vertex(135 + 21)],
-  [Vertex(136 - 1), Vertex(136 + 1), Vertex(136 - 21), Vertex(136 + 21)],
-  [Vertex(137 - 1), Vertex(137 + 1), Vertex(137 - 21), Vertex(137 + 21)],
-  [Vertex(138 - 1), Vertex(138 + 1), Vertex(138 - 21), Vertex(138 + 21)],
-  [Vertex(139 - 1), Vertex(139 + 1), Vertex(139 - 21), Vertex(139 + 21)],
-  [Vertex(140 - 1), Vertex(140 + 1), Vertex(140 - 21), Vertex(140 + 21)],
-  [Vertex(141 - 1), Vertex(141 + 1), Vertex(141 - 21), Vertex(141 + 21)],
-  [Vertex(142 - 1), Vertex(142 + 1), Vertex(142 - 21), Vertex(142 + 21)],
-  [Vertex(143 - 1), Vertex(143 + 1), Vertex(143 - 21), Vertex(143 + 21)],
-  [Vertex(144 - 1), Vertex(144 + 1), Vertex(144 - 21), Vertex(144 + 21)],
-  [Vertex(145 - 1), Vertex(145 + 1), Vertex(145 - 21), Vertex(145 + 21)],
-  [Vertex(146 - 1), Vertex(146 + 1), Vertex(146 - 21), Vertex(146 + 21)],
-  [Vertex(147 - 1), Vertex(147 + 1), Vertex(147 - 21), Vertex(147 + 21)],
-  [Vertex(148 - 1), Vertex(148 + 1), Vertex(148 - 21), Vertex(148 + 21)],
-  [Vertex(149 - 1), Vertex(149 + 1), Vertex(149 - 21), Vertex(149 + 21)],
-  [Vertex(150 - 1), Vertex(150 + 1), Vertex(150 - 21), Vertex(150 + 21)],
-  [Vertex(151 - 1), Vertex(151 + 1), Vertex(151 - 21), Vertex(151 + 21)],
-  [Vertex(152 - 1), Vertex(152 + 1), Vertex(152 - 21), Vertex(152 + 21)],
-  [Vertex(153 - 1), Vertex(153 + 1), Vertex(153 - 21), Vertex(153 + 21)],
-  [Vertex(154 - 1), Vertex(154 + 1), Vertex(154 - 21), Vertex(154 + 21)],
-  [Vertex(155 - 1), Vertex(155 + 1), Vertex(155 - 21), Vertex(155 + 21)],
-  [Vertex(156 - 1), Vertex(156 + 1), Vertex(156 - 21), Vertex(156 + 21)],
-  [Vertex(157 - 1), Vertex(157 + 1), Vertex(157 - 21), Vertex(157 + 21)],
-  [Vertex(158 - 1), Vertex(158 + 1), Vertex(158 - 21), Vertex(158 + 21)],
-  [Vertex(159 - 1), Vertex(159 + 1), Vertex(159 - 21), Vertex(159 + 21)],
-  [Vertex(160 - 1), Vertex(160 + 1), Vertex(160 - 21), Vertex(160 + 21)],
-  [Vertex(161 - 1), Vertex(161 + 1), Vertex(161 - 21), Vertex(161 + 21)],
-  [Vertex(162 - 1), Vertex(162 + 1), Vertex(162 - 21), Vertex(162 + 21)],
-  [Vertex(163 - 1), Vertex(163 + 1), Vertex(163 - 21), Vertex(163 + 21)],
-  [Vertex(164 - 1), Vertex(164 + 1), Vertex(164 - 21), Vertex(164 + 21)],
-  [Vertex(165 - 1), Vertex(165 + 1), Vertex(165 - 21), Vertex(165 + 21)],
-  [Vertex(166 - 1), Vertex(166 + 1), Vertex(166 - 21), Vertex(166 + 21)],
-  [Vertex(167 - 1), Vertex(167 + 1), Vertex(167 - 21), Vertex(167 + 21)],
-  [Vertex(168 - 1), Vertex(168 + 1), Vertex(168 - 21), Vertex(168 + 21)],
-  [Vertex(169 - 1), Vertex(169 + 1), Vertex(169 - 21), Vertex(169 + 21)],
-  [Vertex(170 - 1), Vertex(170 + 1), Vertex(170 - 21), Vertex(170 + 21)],
-  [Vertex(171 - 1), Vertex(171 + 1), Vertex(171 - 21), Vertex(171 + 21)],
-  [Vertex(172 - 1), Vertex(172 + 1), Vertex(172 - 21), Vertex(172 + 21)],
-  [Vertex(173 - 1), Vertex(173 + 1), Vertex(173 - 21), Vertex(173 + 21)],
-  [Vertex(174 - 1), Vertex(174 + 1), Vertex(174 - 21), Vertex(174 + 21)],
-  [Vertex(175 - 1), Vertex(175 + 1), Vertex(175 - 21), Vertex(175 + 21)],
-  [Vertex(176 - 1), Vertex(176 + 1), Vertex(176 - 21), Vertex(176 + 21)],
-  [Vertex(177 - 1), Vertex(177 + 1), Vertex(177 - 21), Vertex(177 + 21)],
-  [Vertex(178 - 1), Vertex(178 + 1), Vertex(178 - 21), Vertex(178 + 21)],
-  [Vertex(179 - 1), Vertex(179 + 1), Vertex(179 - 21), Vertex(179 + 21)],
-  [Vertex(180 - 1), Vertex(180 + 1), Vertex(180 - 21), Vertex(180 + 21)],
-  [Vertex(181 - 1), Vertex(181 + 1), Vertex(181 - 21), Vertex(181 + 21)],
-  [Vertex(182 - 1), Vertex(182 + 1), Vertex(182 - 21), Vertex(182 + 21)],
-  [Vertex(183 - 1), Vertex(183 + 1), Vertex(183 - 21), Vertex(183 + 21)],
-  [Vertex(184 - 1), Vertex(184 + 1), Vertex(184 - 21), Vertex(184 + 21)],
-  [Vertex(185 - 1), Vertex(185 + 1), Vertex(185 - 21), Vertex(185 + 21)],
-  [Vertex(186 - 1), Vertex(186 + 1), Vertex(186 - 21), Vertex(186 + 21)],
-  [Vertex(187 - 1), Vertex(187 + 1), Vertex(187 - 21), Vertex(187 + 21)],
-  [Vertex(188 - 1), Vertex(188 + 1), Vertex(188 - 21), Vertex(188 + 21)],
-  [Vertex(189 - 1), Vertex(189 + 1), Vertex(189 - 21), Vertex(189 + 21)],
-  [Vertex(190 - 1), Vertex(190 + 1), Vertex(190 - 21), Vertex(190 + 21)],
-  [Vertex(191 - 1), Vertex(191 + 1), Vertex(191 - 21), Vertex(191 + 21)],
-  [Vertex(192 - 1), Vertex(192 + 1), Vertex(192 - 21), Vertex(192 + 21)],
-  [Vertex(193 - 1), Vertex(193 + 1), Vertex(193 - 21), Vertex(193 + 21)],
-  [Vertex(194 - 1), Vertex(194 + 1), Vertex(194 - 21), Vertex(194 + 21)],
-  [Vertex(195 - 1), Vertex(195 + 1), Vertex(195 - 21), Vertex(195 + 21)],
-  [Vertex(196 - 1), Vertex(196 + 1), Vertex(196 - 21), Vertex(196 + 21)],
-  [Vertex(197 - 1), Vertex(197 + 1), Vertex(197 - 21), Vertex(197 + 21)],
-  [Vertex(198 - 1), Vertex(198 + 1), Vertex(198 - 21), Vertex(198 + 21)],
-  [Vertex(199 - 1), Vertex(199 + 1), Vertex(199 - 21), Vertex(199 + 21)],
-  [Vertex(200 - 1), Vertex(200 + 1), Vertex(200 - 21), Vertex(200 + 21)],
-  [Vertex(201 - 1), Vertex(201 + 1), Vertex(201 - 21), Vertex(201 + 21)],
-  [Vertex(202 - 1), Vertex(202 + 1), Vertex(202 - 21), Vertex(202 + 21)],
-  [Vertex(203 - 1), Vertex(203 + 1), Vertex(203 - 21), Vertex(203 + 21)],
-  [Vertex(204 - 1), Vertex(204 + 1), Vertex(204 - 21), Vertex(204 + 21)],
-  [Vertex(205 - 1), Vertex(205 + 1), Vertex(205 - 21), Vertex(205 + 21)],
-  [Vertex(206 - 1), Vertex(206 + 1), Vertex(206 - 21), Vertex(206 + 21)],
-  [Vertex(207 - 1), Vertex(207 + 1), Vertex(207 - 21), Vertex(207 + 21)],
-  [Vertex(208 - 1), Vertex(208 + 1), Vertex(208 - 21), Vertex(208 + 21)],
-  [Vertex(209 - 1), Vertex(209 + 1), Vertex(209 - 21), Vertex(209 + 21)],
-  [Vertex(210 - 1), Vertex(210 + 1), Vertex(210 - 21), Vertex(210 + 21)],
-  [Vertex(211 - 1), Vertex(211 + 1), Vertex(211 - 21), Vertex(211 + 21)],
-  [Vertex(212 - 1), Vertex(212 + 1), Vertex(212 - 21), Vertex(212 + 21)],
-  [Vertex(213 - 1), Vertex(213 + 1), Vertex(213 - 21), Vertex(213 + 21)],
-  [Vertex(214 - 1), Vertex(214 + 1), Vertex(214 - 21), Vertex(214 + 21)],
-  [Vertex(215 - 1), Vertex(215 + 1), Vertex(215 - 21), Vertex(215 + 21)],
-  [Vertex(216 - 1), Vertex(216 + 1), Vertex(216 - 21), Vertex(216 + 21)],
-  [Vertex(217 - 1), Vertex(217 + 1), Vertex(217 - 21), Vertex(217 + 21)],
-  [Vertex(218 - 1), Vertex(218 + 1), Vertex(218 - 21), Vertex(218 + 21)],
-  [Vertex(219 - 1), Vertex(219 + 1), Vertex(219 - 21), Vertex(219 + 21)],
-  [Vertex(220 - 1), Vertex(220 + 1), Vertex(220 - 21), Vertex(220 + 21)],
-  [Vertex(221 - 1), Vertex(221 + 1), Vertex(221 - 21), Vertex(221 + 21)],
-  [Vertex(222 - 1), Vertex(222 + 1), Vertex(222 - 21), Vertex(222 + 21)],
-  [Vertex(223 - 1), Vertex(223 + 1), Vertex(223 - 21), Vertex(223 + 21)],
-  [Vertex(224 - 1), Vertex(224 + 1), Vertex(224 - 21), Vertex(224 + 21)],
-  [Vertex(225 - 1), Vertex(225 + 1), Vertex(225 - 21), Vertex(225 + 21)],
-  [Vertex(226 - 1), Vertex(226 + 1), Vertex(226 - 21), Vertex(226 + 21)],
-  [Vertex(227 - 1), Vertex(227 + 1), Vertex(227 - 21), Vertex(227 + 21)],
-  [Vertex(228 - 1), Vertex(228 + 1), Vertex(228 - 21), Vertex(228 + 21)],
-  [Vertex(229 - 1), Vertex(229 + 1), Vertex(229 - 21), Vertex(229 + 21)],
-  [Vertex(230 - 1), Vertex(230 + 1), Vertex(230 - 21), Vertex(230 + 21)],
-  [Vertex(231 - 1), Vertex(231 + 1), Vertex(231 - 21), Vertex(231 + 21)],
-  [Vertex(232 - 1), Vertex(232 + 1), Vertex(232 - 21), Vertex(232 + 21)],
-  [Vertex(233 - 1), Vertex(233 + 1), Vertex(233 - 21), Vertex(233 + 21)],
-  [Vertex(234 - 1), Vertex(234 + 1), Vertex(234 - 21), Vertex(234 + 21)],
-  [Vertex(235 - 1), Vertex(235 + 1), Vertex(235 - 21), Vertex(235 + 21)],
-  [Vertex(236 - 1), Vertex(236 + 1), Vertex(236 - 21), Vertex(236 + 21)],
-  [Vertex(237 - 1), Vertex(237 + 1), Vertex(237 - 21), Vertex(237 + 21)],
-  [Vertex(238 - 1), Vertex(238 + 1), Vertex(238 - 21), Vertex(238 + 21)],
-  [Vertex(239 - 1), Vertex(239 + 1), Vertex(239 - 21), Vertex(239 + 21)],
-  [Vertex(240 - 1), Vertex(240 + 1), Vertex(240 - 21), Vertex(240 + 21)],
-  [Vertex(241 - 1), Vertex(241 + 1), Vertex(241 - 21), Vertex(241 + 21)],
-  [Vertex(242 - 1), Vertex(242 + 1), Vertex(242 - 21), Vertex(242 + 21)],
-  [Vertex(243 - 1), Vertex(243 + 1), Vertex(243 - 21), Vertex(243 + 21)],
-  [Vertex(244 - 1), Vertex(244 + 1), Vertex(244 - 21), Vertex(244 + 21)],
-  [Vertex(245 - 1), Vertex(245 + 1), Vertex(245 - 21), Vertex(245 + 21)],
-  [Vertex(246 - 1), Vertex(246 + 1), Vertex(246 - 21), Vertex(246 + 21)],
-  [Vertex(247 - 1), Vertex(247 + 1), Vertex(247 - 21), Vertex(247 + 21)],
-  [Vertex(248 - 1), Vertex(248 + 1), Vertex(248 - 21), Vertex(248 + 21)],
-  [Vertex(249 - 1), Vertex(249 + 1), Vertex(249 - 21), Vertex(249 + 21)],
-  [Vertex(250 - 1), Vertex(250 + 1), Vertex(250 - 21), Vertex(250 + 21)],
-  [Vertex(251 - 1), Vertex(251 + 1), Vertex(251 - 21), Vertex(251 + 21)],
-  [Vertex(252 - 1), Vertex(252 + 1), Vertex(252 - 21), Vertex(252 + 21)],
-  [Vertex(253 - 1), Vertex(253 + 1), Vertex(253 - 21), Vertex(253 + 21)],
-  [Vertex(254 - 1), Vertex(254 + 1), Vertex(254 - 21), Vertex(254 + 21)],
-  [Vertex(255 - 1), Vertex(255 + 1), Vertex(255 - 21), Vertex(255 + 21)],
-  [Vertex(256 - 1), Vertex(256 + 1), Vertex(256 - 21), Vertex(256 + 21)],
-  [Vertex(257 - 1), Vertex(257 + 1), Vertex(257 - 21), Vertex(257 + 21)],
-  [Vertex(258 - 1), Vertex(258 + 1), Vertex(258 - 21), Vertex(258 + 21)],
-  [Vertex(259 - 1), Vertex(259 + 1), Vertex(259 - 21), Vertex(259 + 21)],
-  [Vertex(260 - 1), Vertex(260 + 1), Vertex(260 - 21), Vertex(260 + 21)],
-  [Vertex(261 - 1), Vertex(261 + 1), Vertex(261 - 21), Vertex(261 + 21)],
-  [Vertex(262 - 1), Vertex(262 + 1), Vertex(262 - 21), Vertex(262 + 21)],
-  [Vertex(263 - 1), Vertex(263 + 1), Vertex(263 - 21), Vertex(263 + 21)],
-  [Vertex(264 - 1), Vertex(264 + 1), Vertex(264 - 21), Vertex(264 + 21)],
-  [Vertex(265 - 1), Vertex(265 + 1), Vertex(265 - 21), Vertex(265 + 21)],
-  [Vertex(266 - 1), Vertex(266 + 1), Vertex(266 - 21), Vertex(266 + 21)],
-  [Vertex(267 - 1), Vertex(267 + 1), Vertex(267 - 21), Vertex(267 + 21)],
-  [Vertex(268 - 1), Vertex(268 + 1), Vertex(268 - 21), Vertex(268 + 21)],
-  [Vertex(269 - 1), Vertex(269 + 1), Vertex(269 - 21), Vertex(269 + 21)],
-  [Vertex(270 - 1), Vertex(270 + 1), Vertex(270 - 21), Vertex(270 + 21)],
-  [Vertex(271 - 1), Vertex(271 + 1), Vertex(271 - 21), Vertex(271 + 21)],
-  [Vertex(272 - 1), Vertex(272 + 1), Vertex(272 - 21), Vertex(272 + 21)],
-  [Vertex(273 - 1), Vertex(273 + 1), Vertex(273 - 21), Vertex(273 + 21)],
-  [Vertex(274 - 1), Vertex(274 + 1), Vertex(274 - 21), Vertex(274 + 21)],
-  [Vertex(275 - 1), Vertex(275 + 1), Vertex(275 - 21), Vertex(275 + 21)],
-  [Vertex(276 - 1), Vertex(276 + 1), Vertex(276 - 21), Vertex(276 + 21)],
-  [Vertex(277 - 1), Vertex(277 + 1), Vertex(277 - 21), Vertex(277 + 21)],
-  [Vertex(278 - 1), Vertex(278 + 1), Vertex(278 - 21), Vertex(278 + 21)],
-  [Vertex(279 - 1), Vertex(279 + 1), Vertex(279 - 21), Vertex(279 + 21)],
-  [Vertex(280 - 1), Vertex(280 + 1), Vertex(280 - 21), Vertex(280 + 21)],
-  [Vertex(281 - 1), Vertex(281 + 1), Vertex(281 - 21), Vertex(281 + 21)],
-  [Vertex(282 - 1), Vertex(282 + 1), Vertex(282 - 21), Vertex(282 + 21)],
-  [Vertex(283 - 1), Vertex(283 + 1), Vertex(283 - 21), Vertex(283 + 21)],
-  [Vertex(284 - 1), Vertex(284 + 1), Vertex(284 - 21), Vertex(284 + 21)],
-  [Vertex(285 - 1), Vertex(285 + 1), Vertex(285 - 21), Vertex(285 + 21)],
-  [Vertex(286 - 1), Vertex(286 + 1), Vertex(286 - 21), Vertex(286 + 21)],
-  [Vertex(287 - 1), Vertex(287 + 1), Vertex(287 - 21), Vertex(287 + 21)],
-  [Vertex(288 - 1), Vertex(288 + 1), Vertex(288 - 21), Vertex(288 + 21)],
-  [Vertex(289 - 1), Vertex(289 + 1), Vertex(289 - 21), Vertex(289 + 21)],
-  [Vertex(290 - 1), Vertex(290 + 1), Vertex(290 - 21), Vertex(290 + 21)],
-  [Vertex(291 - 1), Vertex(291 + 1), Vertex(291 - 21), Vertex(291 + 21)],
-  [Vertex(292 - 1), Vertex(292 + 1), Vertex(292 - 21), Vertex(292 + 21)],
-  [Vertex(293 - 1), Vertex(293 + 1), Vertex(293 - 21), Vertex(293 + 21)],
-  [Vertex(294 - 1), Vertex(294 + 1), Vertex(294 - 21), Vertex(294 + 21)],
-  [Vertex(295 - 1), Vertex(295 + 1), Vertex(295 - 21), Vertex(295 + 21)],
-  [Vertex(296 - 1), Vertex(296 + 1), Vertex(296 - 21), Vertex(296 + 21)],
-  [Vertex(297 - 1), Vertex(297 + 1), Vertex(297 - 21), Vertex(297 + 21)],
-  [Vertex(298 - 1), Vertex(298 + 1), Vertex(298 - 21), Vertex(298 + 21)],
-  [Vertex(299 - 1), Vertex(299 + 1), Vertex(299 - 21), Vertex(299 + 21)],
-  [Vertex(300 - 1), Vertex(300 + 1), Vertex(300 - 21), Vertex(300 + 21)],
-  [Vertex(301 - 1), Vertex(301 + 1), Vertex(301 - 21), Vertex(301 + 21)],
-  [Vertex(302 - 1), Vertex(302 + 1), Vertex(302 - 21), Vertex(302 + 21)],
-  [Vertex(303 - 1), Vertex(303 + 1), Vertex(303 - 21), Vertex(303 + 21)],
-  [Vertex(304 - 1), Vertex(304 + 1), Vertex(304 - 21), Vertex(304 + 21)],
-  [Vertex(305 - 1), Vertex(305 + 1), Vertex(305 - 21), Vertex(305 + 21)],
-  [Vertex(306 - 1), Vertex(306 + 1), Vertex(306 - 21), Vertex(306 + 21)],
-  [Vertex(307 - 1), Vertex(307 + 1), Vertex(307 - 21), Vertex(307 + 21)],
-  [Vertex(308 - 1), Vertex(308 + 1), Vertex(308 - 21), Vertex(308 + 21)],
-  [Vertex(309 - 1), Vertex(309 + 1), Vertex(309 - 21), Vertex(309 + 21)],
-  [Vertex(310 - 1), Vertex(310 + 1), Vertex(310 - 21), Vertex(310 + 21)],
-  [Vertex(311 - 1), Vertex(311 + 1), Vertex(311 - 21), Vertex(311 + 21)],
-  [Vertex(312 - 1), Vertex(312 + 1), Vertex(312 - 21), Vertex(312 + 21)],
-  [Vertex(313 - 1), Vertex(313 + 1), Vertex(313 - 21), Vertex(313 + 21)],
-  [Vertex(314 - 1), Vertex(314 + 1), Vertex(314 - 21), Vertex(314 + 21)],
-  [Vertex(315 - 1), Vertex(315 + 1), Vertex(315 - 21), Vertex(315 + 21)],
-  [Vertex(316 - 1), Vertex(316 + 1), Vertex(316 - 21), Vertex(316 + 21)],
-  [Vertex(317 - 1), Vertex(317 + 1), Vertex(317 - 21), Vertex(317 + 21)],
-  [Vertex(318 - 1), Vertex(318 + 1), Vertex(318 - 21), Vertex(318 + 21)],
-  [Vertex(319 - 1), Vertex(319 + 1), Vertex(319 - 21), Vertex(319 + 21)],
-  [Vertex(320 - 1), Vertex(320 + 1), Vertex(320 - 21), Vertex(320 + 21)],
-  [Vertex(321 - 1), Vertex(321 + 1), Vertex(321 - 21), Vertex(321 + 21)],
-  [Vertex(322 - 1), Vertex(322 + 1), Vertex(322 - 21), Vertex(322 + 21)],
-  [Vertex(323 - 1), Vertex(323 + 1), Vertex(323 - 21), Vertex(323 + 21)],
-  [Vertex(324 - 1), Vertex(324 + 1), Vertex(324 - 21), Vertex(324 + 21)],
-  [Vertex(325 - 1), Vertex(325 + 1), Vertex(325 - 21), Vertex(325 + 21)],
-  [Vertex(326 - 1), Vertex(326 + 1), Vertex(326 - 21), Vertex(326 + 21)],
-  [Vertex(327 - 1), Vertex(327 + 1), Vertex(327 - 21), Vertex(327 + 21)],
-  [Vertex(328 - 1), Vertex(328 + 1), Vertex(328 - 21), Vertex(328 + 21)],
-  [Vertex(329 - 1), Vertex(329 + 1), Vertex(329 - 21), Vertex(329 + 21)],
-  [Vertex(330 - 1), Vertex(330 + 1), Vertex(330 - 21), Vertex(330 + 21)],
-  [Vertex(331 - 1), Vertex(331 + 1), Vertex(331 - 21), Vertex(331 + 21)],
-  [Vertex(332 - 1), Vertex(332 + 1), Vertex(332 - 21), Vertex(332 + 21)],
-  [Vertex(333 - 1), Vertex(333 + 1), Vertex(333 - 21), Vertex(333 + 21)],
-  [Vertex(334 - 1), Vertex(334 + 1), Vertex(334 - 21), Vertex(334 + 21)],
-  [Vertex(335 - 1), Vertex(335 + 1), Vertex(335 - 21), Vertex(335 + 21)],
-  [Vertex(336 - 1), Vertex(336 + 1), Vertex(336 - 21), Vertex(336 + 21)],
-  [Vertex(337 - 1), Vertex(337 + 1), Vertex(337 - 21), Vertex(337 + 21)],
-  [Vertex(338 - 1), Vertex(338 + 1), Vertex(338 - 21), Vertex(338 + 21)],
-  [Vertex(339 - 1), Vertex(339 + 1), Vertex(339 - 21), Vertex(339 + 21)],
-  [Vertex(340 - 1), Vertex(340 + 1), Vertex(340 - 21), Vertex(340 + 21)],
-  [Vertex(341 - 1), Vertex(341 + 1), Vertex(341 - 21), Vertex(341 + 21)],
-  [Vertex(342 - 1), Vertex(342 + 1), Vertex(342 - 21), Vertex(342 + 21)],
-  [Vertex(343 - 1), Vertex(343 + 1), Vertex(343 - 21), Vertex(343 + 21)],
-  [Vertex(344 - 1), Vertex(344 + 1), Vertex(344 - 21), Vertex(344 + 21)],
-  [Vertex(345 - 1), Vertex(345 + 1), Vertex(345 - 21), Vertex(345 + 21)],
-  [Vertex(346 - 1), Vertex(346 + 1), Vertex(346 - 21), Vertex(346 + 21)],
-  [Vertex(347 - 1), Vertex(347 + 1), Vertex(347 - 21), Vertex(347 + 21)],
-  [Vertex(348 - 1), Vertex(348 + 1), Vertex(348 - 21), Vertex(348 + 21)],
-  [Vertex(349 - 1), Vertex(349 + 1), Vertex(349 - 21), Vertex(349 + 21)],
-  [Vertex(350 - 1), Vertex(350 + 1), Vertex(350 - 21), Vertex(350 + 21)],
-  [Vertex(351 - 1), Vertex(351 + 1), Vertex(351 - 21), Vertex(351 + 21)],
-  [Vertex(352 - 1), Vertex(352 + 1), Vertex(352 - 21), Vertex(352 + 21)],
-  [Vertex(353 - 1), Vertex(353 + 1), Vertex(353 - 21), Vertex(353 + 21)],
-  [Vertex(354 - 1), Vertex(354 + 1), Vertex(354 - 21), Vertex(354 + 21)],
-  [Vertex(355 - 1), Vertex(355 + 1), Vertex(355 - 21), Vertex(355 + 21)],
-  [Vertex(356 - 1), Vertex(356 + 1), Vertex(356 - 21), Vertex(356 + 21)],
-  [Vertex(357 - 1), Vertex(357 + 1), Vertex(357 - 21), Vertex(357 + 21)],
-  [Vertex(358 - 1), Vertex(358 + 1), Vertex(358 - 21), Vertex(358 + 21)],
-  [Vertex(359 - 1), Vertex(359 + 1), Vertex(359 - 21), Vertex(359 + 21)],
-  [Vertex(360 - 1), Vertex(360 + 1), Vertex(360 - 21), Vertex(360 + 21)],
-  [Vertex(361 - 1), Vertex(361 + 1), Vertex(361 - 21), Vertex(361 + 21)],
-  [Vertex(362 - 1), Vertex(362 + 1), Vertex(362 - 21), Vertex(362 + 21)],
-  [Vertex(363 - 1), Vertex(363 + 1), Vertex(363 - 21), Vertex(363 + 21)],
-  [Vertex(364 - 1), Vertex(364 + 1), Vertex(364 - 21), Vertex(364 + 21)],
-  [Vertex(365 - 1), Vertex(365 + 1), Vertex(365 - 21), Vertex(365 + 21)],
-  [Vertex(366 - 1), Vertex(366 + 1), Vertex(366 - 21), Vertex(366 + 21)],
-  [Vertex(367 - 1), Vertex(367 + 1), Vertex(367 - 21), Vertex(367 + 21)],
-  [Vertex(368 - 1), Vertex(368 + 1), Vertex(368 - 21), Vertex(368 + 21)],
-  [Vertex(369 - 1), Vertex(369 + 1), Vertex(369 - 21), Vertex(369 + 21)],
-  [Vertex(370 - 1), Vertex(370 + 1), Vertex(370 - 21), Vertex(370 + 21)],
-  [Vertex(371 - 1), Vertex(371 + 1), Vertex(371 - 21), Vertex(371 + 21)],
-  [Vertex(372 - 1), Vertex(372 + 1), Vertex(372 - 21), Vertex(372 + 21)],
-  [Vertex(373 - 1), Vertex(373 + 1), Vertex(373 - 21), Vertex(373 + 21)],
-  [Vertex(374 - 1), Vertex(374 + 1), Vertex(374 - 21), Vertex(374 + 21)],
-  [Vertex(375 - 1), Vertex(375 + 1), Vertex(375 - 21), Vertex(375 + 21)],
-  [Vertex(376 - 1), Vertex(376 + 1), Vertex(376 - 21), Vertex(376 + 21)],
-  [Vertex(377 - 1), Vertex(377 + 1), Vertex(377 - 21), Vertex(377 + 21)],
-  [Vertex(378 - 1), Vertex(378 + 1), Vertex(378 - 21), Vertex(378 + 21)],
-  [Vertex(379 - 1), Vertex(379 + 1), Vertex(379 - 21), Vertex(379 + 21)],
-  [Vertex(380 - 1), Vertex(380 + 1), Vertex(380 - 21), Vertex(380 + 21)],
-  [Vertex(381 - 1), Vertex(381 + 1), Vertex(381 - 21), Vertex(381 + 21)],
-  [Vertex(382 - 1), Vertex(382 + 1), Vertex(382 - 21), Vertex(382 + 21)],
-  [Vertex(383 - 1), Vertex(383 + 1), Vertex(383 - 21), Vertex(383 + 21)],
-  [Vertex(384 - 1), Vertex(384 + 1), Vertex(384 - 21), Vertex(384 + 21)],
-  [Vertex(385 - 1), Vertex(385 + 1), Vertex(385 - 21), Vertex(385 + 21)],
-  [Vertex(386 - 1), Vertex(386 + 1), Vertex(386 - 21), Vertex(386 + 21)],
-  [Vertex(387 - 1), Vertex(387 + 1), Vertex(387 - 21), Vertex(387 + 21)],
-  [Vertex(388 - 1), Vertex(388 + 1), Vertex(388 - 21), Vertex(388 + 21)],
-  [Vertex(389 - 1), Vertex(389 + 1), Vertex(389 - 21), Vertex(389 + 21)],
-  [Vertex(390 - 1), Vertex(390 + 1), Vertex(390 - 21), Vertex(390 + 21)],
-  [Vertex(391 - 1), Vertex(391 + 1), Vertex(391 - 21), Vertex(391 + 21)],
-  [Vertex(392 - 1), Vertex(392 + 1), Vertex(392 - 21), Vertex(392 + 21)],
-  [Vertex(393 - 1), Vertex(393 + 1), Vertex(393 - 21), Vertex(393 + 21)],
-  [Vertex(394 - 1), Vertex(394 + 1), Vertex(394 - 21), Vertex(394 + 21)],
-  [Vertex(395 - 1), Vertex(395 + 1), Vertex(395 - 21), Vertex(395 + 21)],
-  [Vertex(396 - 1), Vertex(396 + 1), Vertex(396 - 21), Vertex(396 + 21)],
-  [Vertex(397 - 1), Vertex(397 + 1), Vertex(397 - 21), Vertex(397 + 21)],
-  [Vertex(398 - 1), Vertex(398 + 1), Vertex(398 - 21), Vertex(398 + 21)],
-  [Vertex(399 - 1), Vertex(399 + 1), Vertex(399 - 21), Vertex(399 + 21)],
-  [Vertex(400 - 1), Vertex(400 + 1), Vertex(400 - 21), Vertex(400 + 21)],
-  [Vertex(401 - 1), Vertex(401 + 1), Vertex(401 - 21), Vertex(401 + 21)],
-  [Vertex(402 - 1), Vertex(402 + 1), Vertex(402 - 21), Vertex(402 + 21)],
-  [Vertex(403 - 1), Vertex(403 + 1), Vertex(403 - 21), Vertex(403 + 21)],
-  [Vertex(404 - 1), Vertex(404 + 1), Vertex(404 - 21), Vertex(404 + 21)],
-  [Vertex(405 - 1), Vertex(405 + 1), Vertex(405 - 21), Vertex(405 + 21)],
-  [Vertex(406 - 1), Vertex(406 + 1), Vertex(406 - 21), Vertex(406 + 21)],
-  [Vertex(407 - 1), Vertex(407 + 1), Vertex(407 - 21), Vertex(407 + 21)],
-  [Vertex(408 - 1), Vertex(408 + 1), Vertex(408 - 21), Vertex(408 + 21)],
-  [Vertex(409 - 1), Vertex(409 + 1), Vertex(409 - 21), Vertex(409 + 21)],
-  [Vertex(410 - 1), Vertex(410 + 1), Vertex(410 - 21), Vertex(410 + 21)],
-  [Vertex(411 - 1), Vertex(411 + 1), Vertex(411 - 21), Vertex(411 + 21)],
-  [Vertex(412 - 1), Vertex(412 + 1), Vertex(412 - 21), Vertex(412 + 21)],
-  [Vertex(413 - 1), Vertex(413 + 1), Vertex(413 - 21), Vertex(413 + 21)],
-  [Vertex(414 - 1), Vertex(414 + 1), Vertex(414 - 21), Vertex(414 + 21)],
-  [Vertex(415 - 1), Vertex(415 + 1), Vertex(415 - 21), Vertex(415 + 21)],
-  [Vertex(416 - 1), Vertex(416 + 1), Vertex(416 - 21), Vertex(416 + 21)],
-  [Vertex(417 - 1), Vertex(417 + 1), Vertex(417 - 21), Vertex(417 + 21)],
-  [Vertex(418 - 1), Vertex(418 + 1), Vertex(418 - 21), Vertex(418 + 21)],
-  [Vertex(419 - 1), Vertex(419 + 1), Vertex(419 - 21), Vertex(419 + 21)],
-  [Vertex(420 - 1), Vertex(420 + 1), Vertex(420 - 21), Vertex(420 + 21)],
-  [Vertex(421 - 1), Vertex(421 + 1), Vertex(421 - 21), Vertex(421 + 21)],
-  [Vertex(422 - 1), Vertex(422 + 1), Vertex(422 - 21), Vertex(422 + 21)],
-  [Vertex(423 - 1), Vertex(423 + 1), Vertex(423 - 21), Vertex(423 + 21)],
-  [Vertex(424 - 1), Vertex(424 + 1), Vertex(424 - 21), Vertex(424 + 21)],
-  [Vertex(425 - 1), Vertex(425 + 1), Vertex(425 - 21), Vertex(425 + 21)],
-  [Vertex(426 - 1), Vertex(426 + 1), Vertex(426 - 21), Vertex(426 + 21)],
-  [Vertex(427 - 1), Vertex(427 + 1), Vertex(427 - 21), Vertex(427 + 21)],
-  [Vertex(428 - 1), Vertex(428 + 1), Vertex(428 - 21), Vertex(428 + 21)],
-  [Vertex(429 - 1), Vertex(429 + 1), Vertex(429 - 21), Vertex(429 + 21)],
-  [Vertex(430 - 1), Vertex(430 + 1), Vertex(430 - 21), Vertex(430 + 21)],
-  [Vertex(431 - 1), Vertex(431 + 1), Vertex(431 - 21), Vertex(431 + 21)],
-  [Vertex(432 - 1), Vertex(432 + 1), Vertex(432 - 21), Vertex(432 + 21)],
-  [Vertex(433 - 1), Vertex(433 + 1), Vertex(433 - 21), Vertex(433 + 21)],
-  [Vertex(434 - 1), Vertex(434 + 1), Vertex(434 - 21), Vertex(434 + 21)],
-  [Vertex(435 - 1), Vertex(435 + 1), Vertex(435 - 21), Vertex(435 + 21)],
-  [Vertex(436 - 1), Vertex(436 + 1), Vertex(436 - 21), Vertex(436 + 21)],
-  [Vertex(437 - 1), Vertex(437 + 1), Vertex(437 - 21), Vertex(437 + 21)],
-  [Vertex(438 - 1), Vertex(438 + 1), Vertex(438 - 21), Vertex(438 + 21)],
-  [Vertex(439 - 1), Vertex(439 + 1), Vertex(439 - 21), Vertex(439 + 21)],
-  [Vertex(440 - 1), Vertex(440 + 1), Vertex(440 - 21), Vertex(440 + 21)]
-];
-
-// print',\n'.join(["[Vertex(%d - 22), Vertex(%d - 20), Vertex(%d + 20), Vertex(%d + 22)]" % (i, i, i, i) for i in range(21 * 21)])
-pub static DIAG_NEIGHBOURS: [[Vertex; 4]; 21 * 21] = [
-  [Vertex(0 - 22), Vertex(0 - 20), Vertex(0 + 20), Vertex(0 + 22)],
-  [Vertex(1 - 22), Vertex(1 - 20), Vertex(1 + 20), Vertex(1 + 22)],
-  [Vertex(2 - 22), Vertex(2 - 20), Vertex(2 + 20), Vertex(2 + 22)],
-  [Vertex(3 - 22), Vertex(3 - 20), Vertex(3 + 20), Vertex(3 + 22)],
-  [Vertex(4 - 22), Vertex(4 - 20), Vertex(4 + 20), Vertex(4 + 22)],
-  [Vertex(5 - 22), Vertex(5 - 20), Vertex(5 + 20), Vertex(5 + 22)],
-  [Vertex(6 - 22), Vertex(6 - 20), Vertex(6 + 20), Vertex(6 + 22)],
-  [Vertex(7 - 22), Vertex(7 - 20), Vertex(7 + 20), Vertex(7 + 22)],
-  [Vertex(8 - 22), Vertex(8 - 20), Vertex(8 + 20), Vertex(8 + 22)],
-  [Vertex(9 - 22), Vertex(9 - 20), Vertex(9 + 20), Vertex(9 + 22)],
-  [Vertex(10 - 22), Vertex(10 - 20), Vertex(10 + 20), Vertex(10 + 22)],
-  [Vertex(11 - 22), Vertex(11 - 20), Vertex(11 + 20), Vertex(11 + 22)],
-  [Vertex(12 - 22), Vertex(12 - 20), Vertex(12 + 20), Vertex(12 + 22)],
-  [Vertex(13 - 22), Vertex(13 - 20), Vertex(13 + 20), Vertex(13 + 22)],
-  [Vertex(14 - 22), Vertex(14 - 20), Vertex(14 + 20), Vertex(14 + 22)],
-  [Vertex(15 - 22), Vertex(15 - 20), Vertex(15 + 20), Vertex(15 + 22)],
-  [Vertex(16 - 22), Vertex(16 - 20), Vertex(16 + 20), Vertex(16 + 22)],
-  [Vertex(17 - 22), Vertex(17 - 20), Vertex(17 + 20), Vertex(17 + 22)],
-  [Vertex(18 - 22), Vertex(18 - 20), Vertex(18 + 20), Vertex(18 + 22)],
-  [Vertex(19 - 22), Vertex(19 - 20), Vertex(19 + 20), Vertex(19 + 22)],
-  [Vertex(20 - 22), Vertex(20 - 20), Vertex(20 + 20), Vertex(20 + 22)],
-  [Vertex(21 - 22), Vertex(21 - 20), Vertex(21 + 20), Vertex(21 + 22)],
-  [Vertex(22 - 22), Vertex(22 - 20), Vertex(22 + 20), Vertex(22 + 22)],
-  [Vertex(23 - 22), Vertex(23 - 20), Vertex(23 + 20), Vertex(23 + 22)],
-  [Vertex(24 - 22), Vertex(24 - 20), Vertex(24 + 20), Vertex(24 + 22)],
-  [Vertex(25 - 22), Vertex(25 - 20), Vertex(25 + 20), Vertex(25 + 22)],
-  [Vertex(26 - 22), Vertex(26 - 20), Vertex(26 + 20), Vertex(26 + 22)],
-  [Vertex(27 - 22), Vertex(27 - 20), Vertex(27 + 20), Vertex(27 + 22)],
-  [Vertex(28 - 22), Vertex(28 - 20), Vertex(28 + 20), Vertex(28 + 22)],
-  [Vertex(29 - 22), Vertex(29 - 20), Vertex(29 + 20), Vertex(29 + 22)],
-  [Vertex(30 - 22), Vertex(30 - 20), Vertex(30 + 20), Vertex(30 + 22)],
-  [Vertex(31 - 22), Vertex(31 - 20), Vertex(31 + 20), Vertex(31 + 22)],
-  [Vertex(32 - 22), Vertex(32 - 20), Vertex(32 + 20), Vertex(32 + 22)],
-  [Vertex(33 - 22), Vertex(33 - 20), Vertex(33 + 20), Vertex(33 + 22)],
-  [Vertex(34 - 22), Vertex(34 - 20), Vertex(34 + 20), Vertex(34 + 22)],
-  [Vertex(35 - 22), Vertex(35 - 20), Vertex(35 + 20), Vertex(35 + 22)],
-  [Vertex(36 - 22), Vertex(36 - 20), Vertex(36 + 20), Vertex(36 + 22)],
-  [Vertex(37 - 22), Vertex(37 - 20), Vertex(37 + 20), Vertex(37 + 22)],
-  [Vertex(38 - 22), Vertex(38 - 20), Vertex(38 + 20), Vertex(38 + 22)],
-  [Vertex(39 - 22), Vertex(39 - 20), Vertex(39 + 20), Vertex(39 + 22)],
-  [Vertex(40 - 22), Vertex(40 - 20), Vertex(40 + 20), Vertex(40 + 22)],
-  [Vertex(41 - 22), Vertex(41 - 20), Vertex(41 + 20), Vertex(41 + 22)],
-  [Vertex(42 - 22), Vertex(42 - 20), Vertex(42 + 20), Vertex(42 + 22)],
-  [Vertex(43 - 22), Vertex(43 - 20), Vertex(43 + 20), Vertex(43 + 22)],
-  [Vertex(44 - 22), Vertex(44 - 20), Vertex(44 + 20), Vertex(44 + 22)],
-  [Vertex(45 - 22), Vertex(45 - 20), Vertex(45 + 20), Vertex(45 + 22)],
-  [Vertex(46 - 22), Vertex(46 - 20), Vertex(46 + 20), Vertex(46 + 22)],
-  [Vertex(47 - 22), Vertex(47 - 20), Vertex(47 + 20), Vertex(47 + 22)],
-  [Vertex(48 - 22), Vertex(48 - 20), Vertex(48 + 20), Vertex(48 + 22)],
-  [Vertex(49 - 22), Vertex(49 - 20), Vertex(49 + 20), Vertex(49 + 22)],
-  [Vertex(50 - 22), Vertex(50 - 20), Vertex(50 + 20), Vertex(50 + 22)],
-  [Vertex(51 - 22), Vertex(51 - 20), Vertex(51 + 20), Vertex(51 + 22)],
-  [Vertex(52 - 22), Vertex(52 - 20), Vertex(52 + 20), Vertex(52 + 22)],
-  [Vertex(53 - 22), Vertex(53 - 20), Vertex(53 + 20), Vertex(53 + 22)],
-  [Vertex(54 - 22), Vertex(54 - 20), Vertex(54 + 20), Vertex(54 + 22)],
-  [Vertex(55 - 22), Vertex(55 - 20), Vertex(55 + 20), Vertex(55 + 22)],
-  [Vertex(56 - 22), Vertex(56 - 20), Vertex(56 + 20), Vertex(56 + 22)],
-  [Vertex(57 - 22), Vertex(57 - 20), Vertex(57 + 20), Vertex(57 + 22)],
-  [Vertex(58 - 22), Vertex(58 - 20), Vertex(58 + 20), Vertex(58 + 22)],
-  [Vertex(59 - 22), Vertex(59 - 20), Vertex(59 + 20), Vertex(59 + 22)],
-  [Vertex(60 - 22), Vertex(60 - 20), Vertex(60 + 20), Vertex(60 + 22)],
-  [Vertex(61 - 22), Vertex(61 - 20), Vertex(61 + 20), Vertex(61 + 22)],
-  [Vertex(62 - 22), Vertex(62 - 20), Vertex(62 + 20), Vertex(62 + 22)],
-  [Vertex(63 - 22), Vertex(63 - 20), Vertex(63 + 20), Vertex(63 + 22)],
-  [Vertex(64 - 22), Vertex(64 - 20), Vertex(64 + 20), Vertex(64 + 22)],
-  [Vertex(65 - 22), Vertex(65 - 20), Vertex(65 + 20), Vertex(65 + 22)],
-  [Vertex(66 - 22), Vertex(66 - 20), Vertex(66 + 20), Vertex(66 + 22)],
-  [Vertex(67 - 22), Vertex(67 - 20), Vertex(67 + 20), Vertex(67 + 22)],
-  [Vertex(68 - 22), Vertex(68 - 20), Vertex(68 + 20), Vertex(68 + 22)],
-  [Vertex(69 - 22), Vertex(69 - 20), Vertex(69 + 20), Vertex(69 + 22)],
-  [Vertex(70 - 22), Vertex(70 - 20), Vertex(70 + 20), Vertex(70 + 22)],
-  [Vertex(71 - 22), Vertex(71 - 20), Vertex(71 + 20), Vertex(71 + 22)],
-  [Vertex(72 - 22), Vertex(72 - 20), Vertex(72 + 20), Vertex(72 + 22)],
-  [Vertex(73 - 22), Vertex(73 - 20), Vertex(73 + 20), Vertex(73 + 22)],
-  [Vertex(74 - 22), Vertex(74 - 20), Vertex(74 + 20), Vertex(74 + 22)],
-  [Vertex(75 - 22), Vertex(75 - 20), Vertex(75 + 20), Vertex(75 + 22)],
-  [Vertex(76 - 22), Vertex(76 - 20), Vertex(76 + 20), Vertex(76 + 22)],
-  [Vertex(77 - 22), Vertex(77 - 20), Vertex(77 + 20), Vertex(77 + 22)],
-  [Vertex(78 - 22), Vertex(78 - 20), Vertex(78 + 20), Vertex(78 + 22)],
-  [Vertex(79 - 22), Vertex(79 - 20), Vertex(79 + 20), Vertex(79 + 22)],
-  [Vertex(80 - 22), Vertex(80 - 20), Vertex(80 + 20), Vertex(80 + 22)],
-  [Vertex(81 - 22), Vertex(81 - 20), Vertex(81 + 20), Vertex(81 + 22)],
-  [Vertex(82 - 22), Vertex(82 - 20), Vertex(82 + 20), Vertex(82 + 22)],
-  [Vertex(83 - 22), Vertex(83 - 20), Vertex(83 + 20), Vertex(83 + 22)],
-  [Vertex(84 - 22), Vertex(84 - 20), Vertex(84 + 20), Vertex(84 + 22)],
-  [Vertex(85 - 22), Vertex(85 - 20), Vertex(85 + 20), Vertex(85 + 22)],
-  [Vertex(86 - 22), Vertex(86 - 20), Vertex(86 + 20), Vertex(86 + 22)],
-  [Vertex(87 - 22), Vertex(87 - 20), Vertex(87 + 20), Vertex(87 + 22)],
-  [Vertex(88 - 22), Vertex(88 - 20), Vertex(88 + 20), Vertex(88 + 22)],
-  [Vertex(89 - 22), Vertex(89 - 20), Vertex(89 + 20), Vertex(89 + 22)],
-  [Vertex(90 - 22), Vertex(90 - 20), Vertex(90 + 20), Vertex(90 + 22)],
-  [Vertex(91 - 22), Vertex(91 - 20), Vertex(91 + 20), Vertex(91 + 22)],
-  [Vertex(92 - 22), Vertex(92 - 20), Vertex(92 + 20), Vertex(92 + 22)],
-  [Vertex(93 - 22), Vertex(93 - 20), Vertex(93 + 20), Vertex(93 + 22)],
-  [Vertex(94 - 22), Vertex(94 - 20), Vertex(94 + 20), Vertex(94 + 22)],
-  [Vertex(95 - 22), Vertex(95 - 20), Vertex(95 + 20), Vertex(95 + 22)],
-  [Vertex(96 - 22), Vertex(96 - 20), Vertex(96 + 20), Vertex(96 + 22)],
-  [Vertex(97 - 22), Vertex(97 - 20), Vertex(97 + 20), Vertex(97 + 22)],
-  [Vertex(98 - 22), Vertex(98 - 20), Vertex(98 + 20), Vertex(98 + 22)],
-  [Vertex(99 - 22), Vertex(99 - 20), Vertex(99 + 20), Vertex(99 + 22)],
-  [Vertex(100 - 22), Vertex(100 - 20), Vertex(100 + 20), Vertex(100 + 22)],
-  [Vertex(101 - 22), Vertex(101 - 20), Vertex(101 + 20), Vertex(101 + 22)],
-  [Vertex(102 - 22), Vertex(102 - 20), Vertex(102 + 20), Vertex(102 + 22)],
-  [Vertex(103 - 22), Vertex(103 - 20), Vertex(103 + 20), Vertex(103 + 22)],
-  [Vertex(104 - 22), Vertex(104 - 20), Vertex(104 + 20), Vertex(104 + 22)],
-  [Vertex(105 - 22), Vertex(105 - 20), Vertex(105 + 20), Vertex(105 + 22)],
-  [Vertex(106 - 22), Vertex(106 - 20), Vertex(106 + 20), Vertex(106 + 22)],
-  [Vertex(107 - 22), Vertex(107 - 20), Vertex(107 + 20), Vertex(107 + 22)],
-  [Vertex(108 - 22), Vertex(108 - 20), Vertex(108 + 20), Vertex(108 + 22)],
-  [Vertex(109 - 22), Vertex(109 - 20), Vertex(109 + 20), Vertex(109 + 22)],
-  [Vertex(110 - 22), Vertex(110 - 20), Vertex(110 + 20), Vertex(110 + 22)],
-  [Vertex(111 - 22), Vertex(111 - 20), Vertex(111 + 20), Vertex(111 + 22)],
-  [Vertex(112 - 22), Vertex(112 - 20), Vertex(112 + 20), Vertex(112 + 22)],
-  [Vertex(113 - 22), Vertex(113 - 20), Vertex(113 + 20), Vertex(113 + 22)],
-  [Vertex(114 - 22), Vertex(114 - 20), Vertex(114 + 20), Vertex(114 + 22)],
-  [Vertex(115 - 22), Vertex(115 - 20), Vertex(115 + 20), Vertex(115 + 22)],
-  [Vertex(116 - 22), Vertex(116 - 20), Vertex(116 + 20), Vertex(116 + 22)],
-  [Vertex(117 - 22), Vertex(117 - 20), Vertex(117 + 20), Vertex(117 + 22)],
-  [Vertex(118 - 22), Vertex(118 - 20), Vertex(118 + 20), Vertex(118 + 22)],
-  [Vertex(119 - 22), Vertex(119 - 20), Vertex(119 + 20), Vertex(119 + 22)],
-  [Vertex(120 - 22), Vertex(120 - 20), Vertex(120 + 20), Vertex(120 + 22)],
-  [Vertex(121 - 22), Vertex(121 - 20), Vertex(121 + 20), Vertex(121 + 22)],
-  [Vertex(122 - 22), Vertex(122 - 20), Vertex(122 + 20), Vertex(122 + 22)],
-  [Vertex(123 - 22), Vertex(123 - 20), Vertex(123 + 20), Vertex(123 + 22)],
-  [Vertex(124 - 22), Vertex(124 - 20), Vertex(124 + 20), Vertex(124 + 22)],
-  [Vertex(125 - 22), Vertex(125 - 20), Vertex(125 + 20), Vertex(125 + 22)],
-  [Vertex(126 - 22), Vertex(126 - 20), Vertex(126 + 20), Vertex(126 + 22)],
-  [Vertex(127 - 22), Vertex(127 - 20), Vertex(127 + 20), Vertex(127 + 22)],
-  [Vertex(128 - 22), Vertex(128 - 20), Vertex(128 + 20), Vertex(128 + 22)],
-  [Vertex(129 - 22), Vertex(129 - 20), Vertex(129 + 20), Vertex(129 + 22)],
-  [Vertex(130 - 22), Vertex(130 - 20), Vertex(130 + 20), Vertex(130 + 22)],
-  [Vertex(131 - 22), Vertex(131 - 20), Vertex(131 + 20), Vertex(131 + 22)],
-  [Vertex(132 - 22), Vertex(132 - 20), Vertex(132 + 20), Vertex(132 + 22)],
-  [Vertex(133 - 22), Vertex(133 - 20), Vertex(133 + 20), Vertex(133 + 22)],
-  [Vertex(134 - 22), Vertex(134 - 20), Vertex(134 + 20), Vertex(134 + 22)],
-  [Vertex(135 - 22), Vertex(135 - 20), Vertex(135 + 20), Vertex(135 + 22)],
-  [Vertex(136 - 22), Vertex(136 - 20), Vertex(136 + 20), Vertex(136 + 22)],
-  [Vertex(137 - 22), Vertex(137 - 20), Vertex(137 + 20), Vertex(137 + 22)],
-  [Vertex(138 - 22), Vertex(138 - 20), Vertex(138 + 20), Vertex(138 + 22)],
-  [Vertex(139 - 22), Vertex(139 - 20), Vertex(139 + 20), Vertex(139 + 22)],
-  [Vertex(140 - 22), Vertex(140 - 20), Vertex(140 + 20), Vertex(140 + 22)],
-  [Vertex(141 - 22), Vertex(141 - 20), Vertex(141 + 20), Vertex(141 + 22)],
-  [Vertex(142 - 22), Vertex(142 - 20), Vertex(142 + 20), Vertex(142 + 22)],
-  [Vertex(143 - 22), Vertex(143 - 20), Vertex(143 + 20), Vertex(143 + 22)],
-  [Vertex(144 - 22), Vertex(144 - 20), Vertex(144 + 20), Vertex(144 + 22)],
-  [Vertex(145 - 22), Vertex(145 - 20), Vertex(145 + 20), Vertex(145 + 22)],
-  [Vertex(146 - 22), Vertex(146 - 20), Vertex(146 + 20), Vertex(146 + 22)],
-  [Vertex(147 - 22), Vertex(147 - 20), Vertex(147 + 20), Vertex(147 + 22)],
-  [Vertex(148 - 22), Vertex(148 - 20), Vertex(148 + 20), Vertex(148 + 22)],
-  [Vertex(149 - 22), Vertex(149 - 20), Vertex(149 + 20), Vertex(149 + 22)],
-  [Vertex(150 - 22), Vertex(150 - 20), Vertex(150 + 20), Vertex(150 + 22)],
-  [Vertex(151 - 22), Vertex(151 - 20), Vertex(151 + 20), Vertex(151 + 22)],
-  [Vertex(152 - 22), Vertex(152 - 20), Vertex(152 + 20), Vertex(152 + 22)],
-  [Vertex(153 - 22), Vertex(153 - 20), Vertex(153 + 20), Vertex(153 + 22)],
-  [Vertex(154 - 22), Vertex(154 - 20), Vertex(154 + 20), Vertex(154 + 22)],
-  [Vertex(155 - 22), Vertex(155 - 20), Vertex(155 + 20), Vertex(155 + 22)],
-  [Vertex(156 - 22), Vertex(156 - 20), Vertex(156 + 20), Vertex(156 + 22)],
-  [Vertex(157 - 22), Vertex(157 - 20), Vertex(157 + 20), Vertex(157 + 22)],
-  [Vertex(158 - 22), Vertex(158 - 20), Vertex(158 + 20), Vertex(158 + 22)],
-  [Vertex(159 - 22), Vertex(159 - 20), Vertex(159 + 20), Vertex(159 + 22)],
-  [Vertex(160 - 22), Vertex(160 - 20), Vertex(160 + 20), Vertex(160 + 22)],
-  [Vertex(161 - 22), Vertex(161 - 20), Vertex(161 + 20), Vertex(161 + 22)],
-  [Vertex(162 - 22), Vertex(162 - 20), Vertex(162 + 20), Vertex(162 + 22)],
-  [Vertex(163 - 22), Vertex(163 - 20), Vertex(163 + 20), Vertex(163 + 22)],
-  [Vertex(164 - 22), Vertex(164 - 20), Vertex(164 + 20), Vertex(164 + 22)],
-  [Vertex(165 - 22), Vertex(165 - 20), Vertex(165 + 20), Vertex(165 + 22)],
-  [Vertex(166 - 22), Vertex(166 - 20), Vertex(166 + 20), Vertex(166 + 22)],
-  [Vertex(167 - 22), Vertex(167 - 20), Vertex(167 + 20), Vertex(167 + 22)],
-  [Vertex(168 - 22), Vertex(168 - 20), Vertex(168 + 20), Vertex(168 + 22)],
-  [Vertex(169 - 22), Vertex(169 - 20), Vertex(169 + 20), Vertex(169 + 22)],
-  [Vertex(170 - 22), Vertex(170 - 20), Vertex(170 + 20), Vertex(170 + 22)],
-  [Vertex(171 - 22), Vertex(171 - 20), Vertex(171 + 20), Vertex(171 + 22)],
-  [Vertex(172 - 22), Vertex(172 - 20), Vertex(172 + 20), Vertex(172 + 22)],
-  [Vertex(173 - 22), Vertex(173 - 20), Vertex(173 + 20), Vertex(173 + 22)],
-  [Vertex(174 - 22), Vertex(174 - 20), Vertex(174 + 20), Vertex(174 + 22)],
-  [Vertex(175 - 22), Vertex(175 - 20), Vertex(175 + 20), Vertex(175 + 22)],
-  [Vertex(176 - 22), Vertex(176 - 20), Vertex(176 + 20), Vertex(176 + 22)],
-  [Vertex(177 - 22), Vertex(177 - 20), Vertex(177 + 20), Vertex(177 + 22)],
-  [Vertex(178 - 22), Vertex(178 - 20), Vertex(178 + 20), Vertex(178 + 22)],
-  [Vertex(179 - 22), Vertex(179 - 20), Vertex(179 + 20), Vertex(179 + 22)],
-  [Vertex(180 - 22), Vertex(180 - 20), Vertex(180 + 20), Vertex(180 + 22)],
-  [Vertex(181 - 22), Vertex(181 - 20), Vertex(181 + 20), Vertex(181 + 22)],
-  [Vertex(182 - 22), Vertex(182 - 20), Vertex(182 + 20), Vertex(182 + 22)],
-  [Vertex(183 - 22), Vertex(183 - 20), Vertex(183 + 20), Vertex(183 + 22)],
-  [Vertex(184 - 22), Vertex(184 - 20), Vertex(184 + 20), Vertex(184 + 22)],
-  [Vertex(185 - 22), Vertex(185 - 20), Vertex(185 + 20), Vertex(185 + 22)],
-  [Vertex(186 - 22), Vertex(186 - 20), Vertex(186 + 20), Vertex(186 + 22)],
-  [Vertex(187 - 22), Vertex(187 - 20), Vertex(187 + 20), Vertex(187 + 22)],
-  [Vertex(188 - 22), Vertex(188 - 20), Vertex(188 + 20), Vertex(188 + 22)],
-  [Vertex(189 - 22), Vertex(189 - 20), Vertex(189 + 20), Vertex(189 + 22)],
-  [Vertex(190 - 22), Vertex(190 - 20), Vertex(190 + 20), Vertex(190 + 22)],
-  [Vertex(191 - 22), Vertex(191 - 20), Vertex(191 + 20), Vertex(191 + 22)],
-  [Vertex(192 - 22), Vertex(192 - 20), Vertex(192 + 20), Vertex(192 + 22)],
-  [Vertex(193 - 22), Vertex(193 - 20), Vertex(193 + 20), Vertex(193 + 22)],
-  [Vertex(194 - 22), Vertex(194 - 20), Vertex(194 + 20), Vertex(194 + 22)],
-  [Vertex(195 - 22), Vertex(195 - 20), Vertex(195 + 20), Vertex(195 + 22)],
-  [Vertex(196 - 22), Vertex(196 - 20), Vertex(196 + 20), Vertex(196 + 22)],
-  [Vertex(197 - 22), Vertex(197 - 20), Vertex(197 + 20), Vertex(197 + 22)],
-  [Vertex(198 - 22), Vertex(198 - 20), Vertex(198 + 20), Vertex(198 + 22)],
-  [Vertex(199 - 22), Vertex(199 - 20), Vertex(199 + 20), Vertex(199 + 22)],
-  [Vertex(200 - 22), Vertex(200 - 20), Vertex(200 + 20), Vertex(200 + 22)],
-  [Vertex(201 - 22), Vertex(201 - 20), Vertex(201 + 20), Vertex(201 + 22)],
-  [Vertex(202 - 22), Vertex(202 - 20), Vertex(202 + 20), Vertex(202 + 22)],
-  [Vertex(203 - 22), Vertex(203 - 20), Vertex(203 + 20), Vertex(203 + 22)],
-  [Vertex(204 - 22), Vertex(204 - 20), Vertex(204 + 20), Vertex(204 + 22)],
-  [Vertex(205 - 22), Vertex(205 - 20), Vertex(205 + 20), Vertex(205 + 22)],
-  [Vertex(206 - 22), Vertex(206 - 20), Vertex(206 + 20), Vertex(206 + 22)],
-  [Vertex(207 - 22), Vertex(207 - 20), Vertex(207 + 20), Vertex(207 + 22)],
-  [Vertex(208 - 22), Vertex(208 - 20), Vertex(208 + 20), Vertex(208 + 22)],
-  [Vertex(209 - 22), Vertex(209 - 20), Vertex(209 + 20), Vertex(209 + 22)],
-  [Vertex(210 - 22), Vertex(210 - 20), Vertex(210 + 20), Vertex(210 + 22)],
-  [Vertex(211 - 22), Vertex(211 - 20), Vertex(211 + 20), Vertex(211 + 22)],
-  [Vertex(212 - 22), Vertex(212 - 20), Vertex(212 + 20), Vertex(212 + 22)],
-  [Vertex(213 - 22), Vertex(213 - 20), Vertex(213 + 20), Vertex(213 + 22)],
-  [Vertex(214 - 22), Vertex(214 - 20), Vertex(214 + 20), Vertex(214 + 22)],
-  [Vertex(215 - 22), Vertex(215 - 20), Vertex(215 + 20), Vertex(215 + 22)],
-  [Vertex(216 - 22), Vertex(216 - 20), Vertex(216 + 20), Vertex(216 + 22)],
-  [Vertex(217 - 22), Vertex(217 - 20), Vertex(217 + 20), Vertex(217 + 22)],
-  [Vertex(218 - 22), Vertex(218 - 20), Vertex(218 + 20), Vertex(218 + 22)],
-  [Vertex(219 - 22), Vertex(219 - 20), Vertex(219 + 20), Vertex(219 + 22)],
-  [Vertex(220 - 22), Vertex(220 - 20), Vertex(220 + 20), Vertex(220 + 22)],
-  [Vertex(221 - 22), Vertex(221 - 20), Vertex(221 + 20), Vertex(221 + 22)],
-  [Vertex(222 - 22), Vertex(222 - 20), Vertex(222 + 20), Vertex(222 + 22)],
-  [Vertex(223 - 22), Vertex(223 - 20), Vertex(223 + 20), Vertex(223 + 22)],
-  [Vertex(224 - 22), Vertex(224 - 20), Vertex(224 + 20), Vertex(224 + 22)],
-  [Vertex(225 - 22), Vertex(225 - 20), Vertex(225 + 20), Vertex(225 + 22)],
-  [Vertex(226 - 22), Vertex(226 - 20), Vertex(226 + 20), Vertex(226 + 22)],
-  [Vertex(227 - 22), Vertex(227 - 20), Vertex(227 + 20), Vertex(227 + 22)],
-  [Vertex(228 - 22), Vertex(228 - 20), Vertex(228 + 20), Vertex(228 + 22)],
-  [Vertex(229 - 22), Vertex(229 - 20), Vertex(229 + 20), Vertex(229 + 22)],
-  [Vertex(230 - 22), Vertex(230 - 20), Vertex(230 + 20), Vertex(230 + 22)],
-  [Vertex(231 - 22), Vertex(231 - 20), Vertex(231 + 20), Vertex(231 + 22)],
-  [Vertex(232 - 22), Vertex(232 - 20), Vertex(232 + 20), Vertex(232 + 22)],
-  [Vertex(233 - 22), Vertex(233 - 20), Vertex(233 + 20), Vertex(233 + 22)],
-  [Vertex(234 - 22), Vertex(234 - 20), Vertex(234 + 20), Vertex(234 + 22)],
-  [Vertex(235 - 22), Vertex(235 - 20), Vertex(235 + 20), Vertex(235 + 22)],
-  [Vertex(236 - 22), Vertex(236 - 20), Vertex(236 + 20), Vertex(236 + 22)],
-  [Vertex(237 - 22), Vertex(237 - 20), Vertex(237 + 20), Vertex(237 + 22)],
-  [Vertex(238 - 22), Vertex(238 - 20), Vertex(238 + 20), Vertex(238 + 22)],
-  [Vertex(239 - 22), Vertex(239 - 20), Vertex(239 + 20), Vertex(239 + 22)],
-  [Vertex(240 - 22), Vertex(240 - 20), Vertex(240 + 20), Vertex(240 + 22)],
-  [Vertex(241 - 22), Vertex(241 - 20), Vertex(241 + 20), Vertex(241 + 22)],
-  [Vertex(242 - 22), Vertex(242 - 20), Vertex(242 + 20), Vertex(242 + 22)],
-  [Vertex(243 - 22), Vertex(243 - 20), Vertex(243 + 20), Vertex(243 + 22)],
-  [Vertex(244 - 22), Vertex(244 - 20), Vertex(244 + 20), Vertex(244 + 22)],
-  [Vertex(245 - 22), Vertex(245 - 20), Vertex(245 + 20), Vertex(245 + 22)],
-  [Vertex(246 - 22), Vertex(246 - 20), Vertex(246 + 20), Vertex(246 + 22)],
-  [Vertex(247 - 22), Vertex(247 - 20), Vertex(247 + 20), Vertex(247 + 22)],
-  [Vertex(248 - 22), Vertex(248 - 20), Vertex(248 + 20), Vertex(248 + 22)],
-  [Vertex(249 - 22), Vertex(249 - 20), Vertex(249 + 20), Vertex(249 + 22)],
-  [Vertex(250 - 22), Vertex(250 - 20), Vertex(250 + 20), Vertex(250 + 22)],
-  [Vertex(251 - 22), Vertex(251 - 20), Vertex(251 + 20), Vertex(251 + 22)],
-  [Vertex(252 - 22), Vertex(252 - 20), Vertex(252 + 20), Vertex(252 + 22)],
-  [Vertex(253 - 22), Vertex(253 - 20), Vertex(253 + 20), Vertex(253 + 22)],
-  [Vertex(254 - 22), Vertex(254 - 20), Vertex(254 + 20), Vertex(254 + 22)],
-  [Vertex(255 - 22), Vertex(255 - 20), Vertex(255 + 20), Vertex(255 + 22)],
-  [Vertex(256 - 22), Vertex(256 - 20), Vertex(256 + 20), Vertex(256 + 22)],
-  [Vertex(257 - 22), Vertex(257 - 20), Vertex(257 + 20), Vertex(257 + 22)],
-  [Vertex(258 - 22), Vertex(258 - 20), Vertex(258 + 20), Vertex(258 + 22)],
-  [Vertex(259 - 22), Vertex(259 - 20), Vertex(259 + 20), Vertex(259 + 22)],
-  [Vertex(260 - 22), Vertex(260 - 20), Vertex(260 + 20), Vertex(260 + 22)],
-  [Vertex(261 - 22), Vertex(261 - 20), Vertex(261 + 20), Vertex(261 + 22)],
-  [Vertex(262 - 22), Vertex(262 - 20), Vertex(262 + 20), Vertex(262 + 22)],
-  [Vertex(263 - 22), Vertex(263 - 20), Vertex(263 + 20), Vertex(263 + 22)],
-  [Vertex(264 - 22), Vertex(264 - 20), Vertex(264 + 20), Vertex(264 + 22)],
-  [Vertex(265 - 22), Vertex(265 - 20), Vertex(265 + 20), Vertex(265 + 22)],
-  [Vertex(266 - 22), Vertex(266 - 20), Vertex(266 + 20), Vertex(266 + 22)],
-  [Vertex(267 - 22), Vertex(267 - 20), Vertex(267 + 20), Vertex(267 + 22)],
-  [Vertex(268 - 22), Vertex(268 - 20), Vertex(268 + 20), Vertex(268 + 22)],
-  [Vertex(269 - 22), Vertex(269 - 20), Vertex(269 + 20), Vertex(269 + 22)],
-  [Vertex(270 - 22), Vertex(270 - 20), Vertex(270 + 20), Vertex(270 + 22)],
-  [Vertex(271 - 22), Vertex(271 - 20), Vertex(271 + 20), Vertex(271 + 22)],
-  [Vertex(272 - 22), Vertex(272 - 20), Vertex(272 + 20), Vertex(272 + 22)],
-  [Vertex(273 - 22), Vertex(273 - 20), Vertex(273 + 20), Vertex(273 + 22)],
-  [Vertex(274 - 22), Vertex(274 - 20), Vertex(274 + 20), Vertex(274 + 22)],
-  [Vertex(275 - 22), Vertex(275 - 20), Vertex(275 + 20), Vertex(275 + 22)],
-  [Vertex(276 - 22), Vertex(276 - 20), Vertex(276 + 20), Vertex(276 + 22)],
-  [Vertex(277 - 22), Vertex(277 - 20), Vertex(277 + 20), Vertex(277 + 22)],
-  [Vertex(278 - 22), Vertex(278 - 20), Vertex(278 + 20), Vertex(278 + 22)],
-  [Vertex(279 - 22), Vertex(279 - 20), Vertex(279 + 20), Vertex(279 + 22)],
-  [Vertex(280 - 22), Vertex(280 - 20), Vertex(280 + 20), Vertex(280 + 22)],
-  [Vertex(281 - 22), Vertex(281 - 20), Vertex(281 + 20), Vertex(281 + 22)],
-  [Vertex(282 - 22), Vertex(282 - 20), Vertex(282 + 20), Vertex(282 + 22)],
-  [Vertex(283 - 22), Vertex(283 - 20), Vertex(283 + 20), Vertex(283 + 22)],
-  [Vertex(284 - 22), Vertex(284 - 20), Vertex(284 + 20), Vertex(284 + 22)],
-  [Vertex(285 - 22), Vertex(285 - 20), Vertex(285 + 20), Vertex(285 + 22)],
-  [Vertex(286 - 22), Vertex(286 - 20), Vertex(286 + 20), Vertex(286 + 22)],
-  [Vertex(287 - 22), Vertex(287 - 20), Vertex(287 + 20), Vertex(287 + 22)],
-  [Vertex(288 - 22), Vertex(288 - 20), Vertex(288 + 20), Vertex(288 + 22)],
-  [Vertex(289 - 22), Vertex(289 - 20), Vertex(289 + 20), Vertex(289 + 22)],
-  [Vertex(290 - 22), Vertex(290 - 20), Vertex(290 + 20), Vertex(290 + 22)],
-  [Vertex(291 - 22), Vertex(291 - 20), Vertex(291 + 20), Vertex(291 + 22)],
-  [Vertex(292 - 22), Vertex(292 - 20), Vertex(292 + 20), Vertex(292 + 22)],
-  [Vertex(293 - 22), Vertex(293 - 20), Vertex(293 + 20), Vertex(293 + 22)],
-  [Vertex(294 - 22), Vertex(294 - 20), Vertex(294 + 20), Vertex(294 + 22)],
-  [Vertex(295 - 22), Vertex(295 - 20), Vertex(295 + 20), Vertex(295 + 22)],
-  [Vertex(296 - 22), Vertex(296 - 20), Vertex(296 + 20), Vertex(296 + 22)],
-  [Vertex(297 - 22), Vertex(297 - 20), Vertex(297 + 20), Vertex(297 + 22)],
-  [Vertex(298 - 22), Vertex(298 - 20), Vertex(298 + 20), Vertex(298 + 22)],
-  [Vertex(299 - 22), Vertex(299 - 20), Vertex(299 + 20), Vertex(299 + 22)],
-  [Vertex(300 - 22), Vertex(300 - 20), Vertex(300 + 20), Vertex(300 + 22)],
-  [Vertex(301 - 22), Vertex(301 - 20), Vertex(301 + 20), Vertex(301 + 22)],
-  [Vertex(302 - 22), Vertex(302 - 20), Vertex(302 + 20), Vertex(302 + 22)],
-  [Vertex(303 - 22), Vertex(303 - 20), Vertex(303 + 20), Vertex(303 + 22)],
-  [Vertex(304 - 22), Vertex(304 - 20), Vertex(304 + 20), Vertex(304 + 22)],
-  [Vertex(305 - 22), Vertex(305 - 20), Vertex(305 + 20), Vertex(305 + 22)],
-  [Vertex(306 - 22), Vertex(306 - 20), Vertex(306 + 20), Vertex(306 + 22)],
-  [Vertex(307 - 22), Vertex(307 - 20), Vertex(307 + 20), Vertex(307 + 22)],
-  [Vertex(308 - 22), Vertex(308 - 20), Vertex(308 + 20), Vertex(308 + 22)],
-  [Vertex(309 - 22), Vertex(309 - 20), Vertex(309 + 20), Vertex(309 + 22)],
-  [Vertex(310 - 22), Vertex(310 - 20), Vertex(310 + 20), Vertex(310 + 22)],
-  [Vertex(311 - 22), Vertex(311 - 20), Vertex(311 + 20), Vertex(311 + 22)],
-  [Vertex(312 - 22), Vertex(312 - 20), Vertex(312 + 20), Vertex(312 + 22)],
-  [Vertex(313 - 22), Vertex(313 - 20), Vertex(313 + 20), Vertex(313 + 22)],
-  [Vertex(314 - 22), Vertex(314 - 20), Vertex(314 + 20), Vertex(314 + 22)],
-  [Vertex(315 - 22), Vertex(315 - 20), Vertex(315 + 20), Vertex(315 + 22)],
-  [Vertex(316 - 22), Vertex(316 - 20), Vertex(316 + 20), Vertex(316 + 22)],
-  [Vertex(317 - 22), Vertex(317 - 20), Vertex(317 + 20), Vertex(317 + 22)],
-  [Vertex(318 - 22), Vertex(318 - 20), Vertex(318 + 20), Vertex(318 + 22)],
-  [Vertex(319 - 22), Vertex(319 - 20), Vertex(319 + 20), Vertex(319 + 22)],
-  [Vertex(320 - 22), Vertex(320 - 20), Vertex(320 + 20), Vertex(320 + 22)],
-  [Vertex(321 - 22), Vertex(321 - 20), Vertex(321 + 20), Vertex(321 + 22)],
-  [Vertex(322 - 22), Vertex(322 - 20), Vertex(322 + 20), Vertex(322 + 22)],
-  [Vertex(323 - 22), Vertex(323 - 20), Vertex(323 + 20), Vertex(323 + 22)],
-  [Vertex(324 - 22), Vertex(324 - 20), Vertex(324 + 20), Vertex(324 + 22)],
-  [Vertex(325 - 22), Vertex(325 - 20), Vertex(325 + 20), Vertex(325 + 22)],
-  [Vertex(326 - 22), Vertex(326 - 20), Vertex(326 + 20), Vertex(326 + 22)],
-  [Vertex(327 - 22), Vertex(327 - 20), Vertex(327 + 20), Vertex(327 + 22)],
-  [Vertex(328 - 22), Vertex(328 - 20), Vertex(328 + 20), Vertex(328 + 22)],
-  [Vertex(329 - 22), Vertex(329 - 20), Vertex(329 + 20), Vertex(329 + 22)],
-  [Vertex(330 - 22), Vertex(330 - 20), Vertex(330 + 20), Vertex(330 + 22)],
-  [Vertex(331 - 22), Vertex(331 - 20), Vertex(331 + 20), Vertex(331 + 22)],
-  [Vertex(332 - 22), Vertex(332 - 20), Vertex(332 + 20), Vertex(332 + 22)],
-  [Vertex(333 - 22), Vertex(333 - 20), Vertex(333 + 20), Vertex(333 + 22)],
-  [Vertex(334 - 22), Vertex(334 - 20), Vertex(334 + 20), Vertex(334 + 22)],
-  [Vertex(335 - 22), Vertex(335 - 20), Vertex(335 + 20), Vertex(335 + 22)],
-  [Vertex(336 - 22), Vertex(336 - 20), Vertex(336 + 20), Vertex(336 + 22)],
-  [Vertex(337 - 22), Vertex(337 - 20), Vertex(337 + 20), Vertex(337 + 22)],
-  [Vertex(338 - 22), Vertex(338 - 20), Vertex(338 + 20), Vertex(338 + 22)],
-  [Vertex(339 - 22), Vertex(339 - 20), Vertex(339 + 20), Vertex(339 + 22)],
-  [Vertex(340 - 22), Vertex(340 - 20), Vertex(340 + 20), Vertex(340 + 22)],
-  [Vertex(341 - 22), Vertex(341 - 20), Vertex(341 + 20), Vertex(341 + 22)],
-  [Vertex(342 - 22), Vertex(342 - 20), Vertex(342 + 20), Vertex(342 + 22)],
-  [Vertex(343 - 22), Vertex(343 - 20), Vertex(343 + 20), Vertex(343 + 22)],
-  [Vertex(344 - 22), Vertex(344 - 20), Vertex(344 + 20), Vertex(344 + 22)],
-  [Vertex(345 - 22), Vertex(345 - 20), Vertex(345 + 20), Vertex(345 + 22)],
-  [Vertex(346 - 22), Vertex(346 - 20), Vertex(346 + 20), Vertex(346 + 22)],
-  [Vertex(347 - 22), Vertex(347 - 20), Vertex(347 + 20), Vertex(347 + 22)],
-  [Vertex(348 - 22), Vertex(348 - 20), Vertex(348 + 20), Vertex(348 + 22)],
-  [Vertex(349 - 22), Vertex(349 - 20), Vertex(349 + 20), Vertex(349 + 22)],
-  [Vertex(350 - 22), Vertex(350 - 20), Vertex(350 + 20), Vertex(350 + 22)],
-  [Vertex(351 - 22), Vertex(351 - 20), Vertex(351 + 20), Vertex(351 + 22)],
-  [Vertex(352 - 22), Vertex(352 - 20), Vertex(352 + 20), Vertex(352 + 22)],
-  [Vertex(353 - 22), Vertex(353 - 20), Vertex(353 + 20), Vertex(353 + 22)],
-  [Vertex(354 - 22), Vertex(354 - 20), Vertex(354 + 20), Vertex(354 + 22)],
-  [Vertex(355 - 22), Vertex(355 - 20), Vertex(355 + 20), Vertex(355 + 22)],
-  [Vertex(356 - 22), Vertex(356 - 20), Vertex(356 + 20), Vertex(356 + 22)],
-  [Vertex(357 - 22), Vertex(357 - 20), Vertex(357 + 20), Vertex(357 + 22)],
-  [Vertex(358 - 22), Vertex(358 - 20), Vertex(358 + 20), Vertex(358 + 22)],
-  [Vertex(359 - 22), Vertex(359 - 20), Vertex(359 + 20), Vertex(359 + 22)],
-  [Vertex(360 - 22), Vertex(360 - 20), Vertex(360 + 20), Vertex(360 + 22)],
-  [Vertex(361 - 22), Vertex(361 - 20), Vertex(361 + 20), Vertex(361 + 22)],
-  [Vertex(362 - 22), Vertex(362 - 20), Vertex(362 + 20), Vertex(362 + 22)],
-  [Vertex(363 - 22), Vertex(363 - 20), Vertex(363 + 20), Vertex(363 + 22)],
-  [Vertex(364 - 22), Vertex(364 - 20), Vertex(364 + 20), Vertex(364 + 22)],
-  [Vertex(365 - 22), Vertex(365 - 20), Vertex(365 + 20), Vertex(365 + 22)],
-  [Vertex(366 - 22), Vertex(366 - 20), Vertex(366 + 20), Vertex(366 + 22)],
-  [Vertex(367 - 22), Vertex(367 - 20), Vertex(367 + 20), Vertex(367 + 22)],
-  [Vertex(368 - 22), Vertex(368 - 20), Vertex(368 + 20), Vertex(368 + 22)],
-  [Vertex(369 - 22), Vertex(369 - 20), Vertex(369 + 20), Vertex(369 + 22)],
-  [Vertex(370 - 22), Vertex(370 - 20), Vertex(370 + 20), Vertex(370 + 22)],
-  [Vertex(371 - 22), Vertex(371 - 20), Vertex(371 + 20), Vertex(371 + 22)],
-  [Vertex(372 - 22), Vertex(372 - 20), Vertex(372 + 20), Vertex(372 + 22)],
-  [Vertex(373 - 22), Vertex(373 - 20), Vertex(373 + 20), Vertex(373 + 22)],
-  [Vertex(374 - 22), Vertex(374 - 20), Vertex(374 + 20), Vertex(374 + 22)],
-  [Vertex(375 - 22), Vertex(375 - 20), Vertex(375 + 20), Vertex(375 + 22)],
-  [Vertex(376 - 22), Vertex(376 - 20), Vertex(376 + 20), Vertex(376 + 22)],
-  [Vertex(377 - 22), Vertex(377 - 20), Vertex(377 + 20), Vertex(377 + 22)],
-  [Vertex(378 - 22), Vertex(378 - 20), Vertex(378 + 20), Vertex(378 + 22)],
-  [Vertex(379 - 22), Vertex(379 - 20), Vertex(379 + 20), Vertex(379 + 22)],
-  [Vertex(380 - 22), Vertex(380 - 20), Vertex(380 + 20), Vertex(380 + 22)],
-  [Vertex(381 - 22), Vertex(381 - 20), Vertex(381 + 20), Vertex(381 + 22)],
-  [Vertex(382 - 22), Vertex(382 - 20), Vertex(382 + 20), Vertex(382 + 22)],
-  [Vertex(383 - 22), Vertex(383 - 20), Vertex(383 + 20), Vertex(383 + 22)],
-  [Vertex(384 - 22), Vertex(384 - 20), Vertex(384 + 20), Vertex(384 + 22)],
-  [Vertex(385 - 22), Vertex(385 - 20), Vertex(385 + 20), Vertex(385 + 22)],
-  [Vertex(386 - 22), Vertex(386 - 20), Vertex(386 + 20), Vertex(386 + 22)],
-  [Vertex(387 - 22), Vertex(387 - 20), Vertex(387 + 20), Vertex(387 + 22)],
-  [Vertex(388 - 22), Vertex(388 - 20), Vertex(388 + 20), Vertex(388 + 22)],
-  [Vertex(389 - 22), Vertex(389 - 20), Vertex(389 + 20), Vertex(389 + 22)],
-  [Vertex(390 - 22), Vertex(390 - 20), Vertex(390 + 20), Vertex(390 + 22)],
-  [Vertex(391 - 22), Vertex(391 - 20), Vertex(391 + 20), Vertex(391 + 22)],
-  [Vertex(392 - 22), Vertex(392 - 20), Vertex(392 + 20), Vertex(392 + 22)],
-  [Vertex(393 - 22), Vertex(393 - 20), Vertex(393 + 20), Vertex(393 + 22)],
-  [Vertex(394 - 22), Vertex(394 - 20), Vertex(394 + 20), Vertex(394 + 22)],
-  [Vertex(395 - 22), Vertex(395 - 20), Vertex(395 + 20), Vertex(395 + 22)],
-  [Vertex(396 - 22), Vertex(396 - 20), Vertex(396 + 20), Vertex(396 + 22)],
-  [Vertex(397 - 22), Vertex(397 - 20), Vertex(397 + 20), Vertex(397 + 22)],
-  [Vertex(398 - 22), Vertex(398 - 20), Vertex(398 + 20), Vertex(398 + 22)],
-  [Vertex(399 - 22), Vertex(399 - 20), Vertex(399 + 20), Vertex(399 + 22)],
-  [Vertex(400 - 22), Vertex(400 - 20), Vertex(400 + 20), Vertex(400 + 22)],
-  [Vertex(401 - 22), Vertex(401 - 20), Vertex(401 + 20), Vertex(401 + 22)],
-  [Vertex(402 - 22), Vertex(402 - 20), Vertex(402 + 20), Vertex(402 + 22)],
-  [Vertex(403 - 22), Vertex(403 - 20), Vertex(403 + 20), Vertex(403 + 22)],
-  [Vertex(404 - 22), Vertex(404 - 20), Vertex(404 + 20), Vertex(404 + 22)],
-  [Vertex(405 - 22), Vertex(405 - 20), Vertex(405 + 20), Vertex(405 + 22)],
-  [Vertex(406 - 22), Vertex(406 - 20), Vertex(406 + 20), Vertex(406 + 22)],
-  [Vertex(407 - 22), Vertex(407 - 20), Vertex(407 + 20), Vertex(407 + 22)],
-  [Vertex(408 - 22), Vertex(408 - 20), Vertex(408 + 20), Vertex(408 + 22)],
-  [Vertex(409 - 22), Vertex(409 - 20), Vertex(409 + 20), Vertex(409 + 22)],
-  [Vertex(410 - 22), Vertex(410 - 20), Vertex(410 + 20), Vertex(410 + 22)],
-  [Vertex(411 - 22), Vertex(411 - 20), Vertex(411 + 20), Vertex(411 + 22)],
-  [Vertex(412 - 22), Vertex(412 - 20), Vertex(412 + 20), Vertex(412 + 22)],
-  [Vertex(413 - 22), Vertex(413 - 20), Vertex(413 + 20), Vertex(413 + 22)],
-  [Vertex(414 - 22), Vertex(414 - 20), Vertex(414 + 20), Vertex(414 + 22)],
-  [Vertex(415 - 22), Vertex(415 - 20), Vertex(415 + 20), Vertex(415 + 22)],
-  [Vertex(416 - 22), Vertex(416 - 20), Vertex(416 + 20), Vertex(416 + 22)],
-  [Vertex(417 - 22), Vertex(417 - 20), Vertex(417 + 20), Vertex(417 + 22)],
-  [Vertex(418 - 22), Vertex(418 - 20), Vertex(418 + 20), Vertex(418 + 22)],
-  [Vertex(419 - 22), Vertex(419 - 20), Vertex(419 + 20), Vertex(419 + 22)],
-  [Vertex(420 - 22), Vertex(420 - 20), Vertex(420 + 20), Vertex(420 + 22)],
-  [Vertex(421 - 22), Vertex(421 - 20), Vertex(421 + 20), Vertex(421 + 22)],
-  [Vertex(422 - 22), Vertex(422 - 20), Vertex(422 + 20), Vertex(422 + 22)],
-  [Vertex(423 - 22), Vertex(423 - 20), Vertex(423 + 20), Vertex(423 + 22)],
-  [Vertex(424 - 22), Vertex(424 - 20), Vertex(424 + 20), Vertex(424 + 22)],
-  [Vertex(425 - 22), Vertex(425 - 20), Vertex(425 + 20), Vertex(425 + 22)],
-  [Vertex(426 - 22), Vertex(426 - 20), Vertex(426 + 20), Vertex(426 + 22)],
-  [Vertex(427 - 22), Vertex(427 - 20), Vertex(427 + 20), Vertex(427 + 22)],
-  [Vertex(428 - 22), Vertex(428 - 20), Vertex(428 + 20), Vertex(428 + 22)],
-  [Vertex(429 - 22), Vertex(429 - 20), Vertex(429 + 20), Vertex(429 + 22)],
-  [Vertex(430 - 22), Vertex(430 - 20), Vertex(430 + 20), Vertex(430 + 22)],
-  [Vertex(431 - 22), Vertex(431 - 20), Vertex(431 + 20), Vertex(431 + 22)],
-  [Vertex(432 - 22), Vertex(432 - 20), Vertex(432 + 20), Vertex(432 + 22)],
-  [Vertex(433 - 22), Vertex(433 - 20), Vertex(433 + 20), Vertex(433 + 22)],
-  [Vertex(434 - 22), Vertex(434 - 20), Vertex(434 + 20), Vertex(434 + 22)],
-  [Vertex(435 - 22), Vertex(435 - 20), Vertex(435 + 20), Vertex(435 + 22)],
-  [Vertex(436 - 22), Vertex(436 - 20), Vertex(436 + 20), Vertex(436 + 22)],
-  [Vertex(437 - 22), Vertex(437 - 20), Vertex(437 + 20), Vertex(437 + 22)],
-  [Vertex(438 - 22), Vertex(438 - 20), Vertex(438 + 20), Vertex(438 + 22)],
-  [Vertex(439 - 22), Vertex(439 - 20), Vertex(439 + 20), Vertex(439 + 22)],
-  [Vertex(440 - 22), Vertex(440 - 20), Vertex(440 + 20), Vertex(440 + 22)]
-];
+// NEIGHBOURS and DIAG_NEIGHBOURS are generated by build.rs for the virtual
+// board of MAX_SIZE.
+include!(concat!(env!("OUT_DIR"), "/neighbours.rs"));
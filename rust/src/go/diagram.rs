@@ -0,0 +1,87 @@
+use std::str;
+use std::string;
+
+use super::GoGame;
+use super::PASS;
+use super::MAX_SIZE;
+use super::stone;
+use super::vertex;
+
+// Parses the diagrams printed by the Debug implementation of GoGame:
+//
+//     ABCDE
+//   5       5
+//   4  #O   4
+//   3 #*#O  3
+//   2  #O   2
+//   1       1
+//     ABCDE
+// white to play
+//
+// Indentation is ignored and '.' may be used for empty points as well. The
+// line with the player to move is optional, by default black is to play.
+impl str::FromStr for GoGame {
+  type Err = string::String;
+
+  fn from_str(s: &str) -> Result<GoGame, string::String> {
+    let mut lines = s.lines().map(|l| l.trim()).filter(|l| !l.is_empty())
+      .collect::<Vec<_>>();
+
+    let mut to_play = stone::BLACK;
+    if lines.last().map_or(false, |l| l.ends_with(" to play")) {
+      let line = lines.pop().unwrap();
+      to_play = try!(line.split(' ').next().unwrap().parse());
+    }
+
+    if lines.len() < 3 {
+      return Err("diagram needs column labels and at least one row".to_string());
+    }
+    let labels = lines[0];
+    let width = labels.len();
+    if width > MAX_SIZE as usize || !vertex::COLUMN_LABELS.starts_with(labels) {
+      return Err(format!("bad column labels '{}'", labels));
+    }
+    if lines[lines.len() - 1] != labels {
+      return Err("column labels differ".to_string());
+    }
+    let height = lines.len() - 2;
+    if height > MAX_SIZE as usize {
+      return Err(format!("more than {} rows", MAX_SIZE));
+    }
+
+    let mut black = vec![];
+    let mut white = vec![];
+    let mut ko_vertex = PASS;
+    for (i, line) in lines[1 .. height + 1].iter().enumerate() {
+      let row = height - i;
+      let label = format!("{}", row);
+      let cells = line.chars().skip(label.len() + 1).take(width).collect::<Vec<_>>();
+      let right_label = line.chars().skip(label.len() + 1 + width).collect::<string::String>();
+      if !line.starts_with(&format!("{} ", label)) || cells.len() != width ||
+          right_label.trim() != label {
+        return Err(format!("bad row '{}', expected row {}", line, row));
+      }
+
+      for (col, c) in cells.iter().enumerate() {
+        let v = GoGame::vertex(col as i16, row as i16 - 1);
+        match *c {
+          '#' => black.push(v),
+          'O' => white.push(v),
+          ' ' | '.' => {},
+          '*' if ko_vertex == PASS => ko_vertex = v,
+          _ => return Err(format!("unexpected '{}' at {}", c, v)),
+        }
+      }
+    }
+
+    let mut game = GoGame::new_rectangular(width, height);
+    game.to_play = to_play;
+    game.setup_stones(stone::BLACK, &black);
+    game.setup_stones(stone::WHITE, &white);
+    if black.iter().chain(white.iter()).any(|v| game.stone_at(*v) == stone::EMPTY) {
+      return Err("strings without liberties".to_string());
+    }
+    game.ko_vertex = ko_vertex;
+    return Ok(game);
+  }
+}
@@ -0,0 +1,105 @@
+use std::cmp;
+
+use super::GoGame;
+use super::Vertex;
+use super::stone;
+
+impl GoGame {
+  // Maximum number of handicap stones for which there is a fixed layout on
+  // the current board.
+  pub fn max_fixed_handicap(&self) -> usize {
+    let size = cmp::min(self.width, self.height);
+    if size < 7 {
+      return 0;
+    }
+    // Without a center line, there are only the four corner star points.
+    if size == 7 || self.width % 2 == 0 || self.height % 2 == 0 {
+      return 4;
+    }
+    return 9;
+  }
+
+  // Star points for num_stones handicap stones, in the standard order used by
+  // GTP fixed_handicap.
+  pub fn fixed_handicap_vertices(&self, num_stones: usize) -> Result<Vec<Vertex>, String> {
+    if num_stones < 2 || num_stones > self.max_fixed_handicap() {
+      return Err("invalid number of stones".to_string());
+    }
+
+    // Star points are on the third line on small boards, the fourth otherwise.
+    let edge = if cmp::min(self.width, self.height) < 13 { 2 } else { 3 };
+    let left = edge;
+    let right = self.width as i16 - 1 - edge;
+    let bottom = edge;
+    let top = self.height as i16 - 1 - edge;
+    let center_x = self.width as i16 / 2;
+    let center_y = self.height as i16 / 2;
+
+    let mut vertices = vec![GoGame::vertex(left, bottom), GoGame::vertex(right, top)];
+    if num_stones >= 3 {
+      vertices.push(GoGame::vertex(left, top));
+    }
+    if num_stones >= 4 {
+      vertices.push(GoGame::vertex(right, bottom));
+    }
+    if num_stones >= 6 {
+      vertices.push(GoGame::vertex(left, center_y));
+      vertices.push(GoGame::vertex(right, center_y));
+    }
+    if num_stones >= 8 {
+      vertices.push(GoGame::vertex(center_x, bottom));
+      vertices.push(GoGame::vertex(center_x, top));
+    }
+    // The center point is used for odd numbers of stones from 5 upwards.
+    if num_stones >= 5 && num_stones % 2 == 1 {
+      vertices.push(GoGame::vertex(center_x, center_y));
+    }
+    return Ok(vertices);
+  }
+
+  // Handicap stones chosen by the engine. Uses the fixed layout where there is
+  // one, further stones are spread out as far as possible from all others.
+  pub fn free_handicap_vertices(&self, num_stones: usize) -> Result<Vec<Vertex>, String> {
+    if num_stones < 2 || num_stones >= self.width * self.height {
+      return Err("invalid number of stones".to_string());
+    }
+
+    let num_fixed = cmp::min(num_stones, self.max_fixed_handicap());
+    let mut vertices = if num_fixed >= 2 {
+      try!(self.fixed_handicap_vertices(num_fixed))
+    } else {
+      vec![]
+    };
+
+    while vertices.len() < num_stones {
+      let mut best = self.empty_vertices[0];
+      let mut best_distance = -1;
+      for v in self.empty_vertices.iter() {
+        if vertices.contains(v) {
+          continue;
+        }
+        let (x, y) = v.to_coords();
+        // Distance to the edge counts double, stones on the first lines are
+        // rarely useful.
+        let mut distance = 2 * cmp::min(cmp::min(x, self.width as i16 - 1 - x),
+                                        cmp::min(y, self.height as i16 - 1 - y));
+        for h in vertices.iter() {
+          let (hx, hy) = h.to_coords();
+          distance = cmp::min(distance, (x - hx).abs() + (y - hy).abs());
+        }
+        if distance > best_distance {
+          best = *v;
+          best_distance = distance;
+        }
+      }
+      vertices.push(best);
+    }
+    return Ok(vertices);
+  }
+
+  // Places black handicap stones on an empty board, white moves next.
+  pub fn place_handicap(&mut self, vertices: &[Vertex]) {
+    self.to_play = stone::WHITE;
+    self.setup_stones(stone::BLACK, vertices);
+  }
+}
@@ -5,6 +5,7 @@ use rand::Rng;
 use std::cmp;
 use std::fmt;
 use std::collections;
+use std::rc;
 
 pub mod stone;
 pub use self::stone::Stone;
@@ -19,19 +20,123 @@ pub use self::constants::DIAG_NEIGHBOURS;
 
 pub mod string;
 pub use self::string::String;
+pub use self::string::Stones;
 
-// Maximum supported board size (width/height).
-const MAX_SIZE: u8 = 19;
+pub mod zobrist;
+pub use self::zobrist::BoardHasher;
+pub use self::zobrist::PosHash;
+
+mod diagram;
+mod handicap;
+mod rules;
+mod score;
+
+pub mod symmetry;
+pub use self::symmetry::NUM_SYMMETRIES;
+
+// The engine doesn't have pattern weights yet, playouts stay uniformly random
+// until it does, see GoGame::set_pattern_weights.
+#[allow(dead_code)]
+pub mod pattern;
+pub use self::pattern::PatternWeights;
+
+pub mod status;
+pub use self::status::StoneStatus;
+
+// Maximum supported board size (width/height). The neighbour tables generated
+// by build.rs need to be updated when changing this.
+pub const MAX_SIZE: u8 = 25;
 // Size of the virtual board necessary to support a board of MAX_SIZE.
 // This includes a one stone border on all sides of the board.
 pub const VIRT_SIZE: u8 = MAX_SIZE + 2;
 // Length of an array/vector necessary to store the virtual board.
 pub const VIRT_LEN: usize = VIRT_SIZE as usize * VIRT_SIZE as usize;
 
+// Which earlier positions a move is not allowed to repeat.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SuperkoRule {
+  // Same stones on the board, no matter who is to play.
+  Positional,
+  // Same stones on the board and the same player to move, e.g. AGA and New
+  // Zealand rules.
+  Situational,
+}
+
+// Everything a single move changed that can't be deduced from the board after
+// the move, so that it can be taken back without replaying the game.
+#[derive(Copy, Clone)]
+struct Undo {
+  to_play: Stone,
+  ko_vertex: Vertex,
+  // Whether the move added new entries to position_hashes and
+  // situation_hashes.
+  new_position: bool,
+  new_situation: bool,
+
+  // Pseudo string, linked list entry and position in empty_vertices of the
+  // vertex before it was played.
+  vertex_string: String,
+  vertex_next_v: Vertex,
+  vertex_empty_index: usize,
+  // Head of the string the new stone was added to and its value before the
+  // move, PASS if the stone started a new string.
+  joined_head: Vertex,
+  joined_string: String,
+  // Neighbour of the new stone, previous head and value of all smaller strings
+  // that were merged into joined_head, in the order they were merged.
+  merged: [(Vertex, Vertex, String); 3],
+  num_merged: usize,
+  // Color, head, value and number of stones of all captured strings, including
+  // our own string for suicide. The stones themselves are on
+  // GoGame::captured_stones, in the order they were removed.
+  captured: [(Stone, Vertex, String, usize); 4],
+  num_captured: usize,
+}
+
+// Reason why GoGame::try_play rejected a move.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum IllegalMove {
+  OffBoard,
+  Occupied,
+  // Immediately retaking a single stone.
+  Ko,
+  Suicide,
+  // Repeating an earlier position, see SuperkoRule.
+  Superko,
+}
+
+impl fmt::Display for IllegalMove {
+  fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+    return write!(f, "{}", match *self {
+      IllegalMove::OffBoard => "off board",
+      IllegalMove::Occupied => "occupied",
+      IllegalMove::Ko => "ko",
+      IllegalMove::Suicide => "suicide",
+      IllegalMove::Superko => "superko",
+    });
+  }
+}
+
+// What a move changed on the board, returned by GoGame::play.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct MoveReport<'a> {
+  // Stones removed from the board, in the order they were removed. Our own
+  // stones in case of suicide.
+  pub captured: &'a [Vertex],
+  // Ids of the first num_merged of our strings that the new stone joined, as
+  // they were before the move, see GoGame::string_id.
+  pub merged: [Vertex; 4],
+  pub num_merged: usize,
+  // Vertex the opponent can't play on because of simple ko, PASS if none.
+  pub ko_vertex: Vertex,
+}
 
 #[derive(Clone)]
 pub struct GoGame {
-  pub size: usize,
+  // Number of columns.
+  pub width: usize,
+  // Number of rows.
+  pub height: usize,
   // Board of stones with a 1-stone border on all sides to remove the need for
   // bound checking. Laid out as 1D vector, see GoGame::vertex for index
   // calculation.
@@ -50,41 +155,104 @@ pub struct GoGame {
   // removal and addition.
   empty_v_index: Vec<usize>,
 
+  // Weights of 3x3 patterns for random_move, which then picks moves with a
+  // probability proportional to the weight of their pattern. The patterns are
+  // only maintained while there are weights, the upkeep slows down every move.
+  pattern_weights: Option<rc::Rc<PatternWeights>>,
+  // The 3x3 patterns of all empty vertices, see Pattern: the colors of the 8
+  // vertices around every vertex, and a bit for each of the 4 adjacent strings
+  // that is in atari.
+  pattern_colors: Vec<u16>,
+  pattern_atari: Vec<u8>,
+  // Vertices whose stone changed and liberties of strings that left atari
+  // during the current move, see update_pattern_atari.
+  changed_vertices: Vec<Vertex>,
+  atari_liberties: Vec<Vertex>,
+
   // Number of black stones on the board, for scoring at the end of the game.
   // WHITE stones can be deduced from this, board size and empty_vertices.
   num_black_stones: i16,
+  // Number of stones of each color that were captured so far, the prisoners of
+  // the opponent for territory scoring.
+  num_captured_black: usize,
+  num_captured_white: usize,
 
   // Vertex that can't be played on because it would be simple ko.
   ko_vertex: Vertex,
 
+  // Zobrist hash of the current position, updated with every stone that is
+  // placed or removed.
+  hasher: rc::Rc<BoardHasher>,
+  hash: PosHash,
+  // Zobrist hashes of all positions that occurred so far, for superko
+  // detection. Situation hashes also include the player to move.
+  position_hashes: collections::HashSet<PosHash>,
+  situation_hashes: collections::HashSet<PosHash>,
+  pub superko_rule: SuperkoRule,
+  // Whether to enforce superko. Checking only simple ko is a lot cheaper and
+  // good enough for random playouts.
+  pub check_superko: bool,
+  // Whether a move may kill its own string (without capturing anything), as in
+  // Tromp-Taylor and New Zealand rules.
+  pub allow_suicide: bool,
+  // Points given to white to compensate for black moving first.
+  pub komi: f32,
+
   pub to_play: Stone,
   pub history: Vec<(Stone, Vertex)>,
+  // One entry for every move in history.
+  undo_stack: Vec<Undo>,
+  captured_stones: Vec<Vertex>,
 }
 
 impl GoGame {
   pub fn new(size: usize) -> GoGame {
-    if size as u8 > MAX_SIZE {
-      panic!("{} is larger than maximum supported board size of {}",
-        size, MAX_SIZE);
+    return GoGame::new_rectangular(size, size);
+  }
+
+  pub fn new_rectangular(width: usize, height: usize) -> GoGame {
+    if width as u8 > MAX_SIZE || height as u8 > MAX_SIZE {
+      panic!("{}x{} is larger than maximum supported board size of {}",
+        width, height, MAX_SIZE);
     }
 
     let mut game = GoGame {
-      size: size,
+      width: width,
+      height: height,
       board: vec![stone::BORDER; VIRT_LEN],
 
       strings: vec![String::new(); VIRT_LEN],
       string_head: vec![PASS; VIRT_LEN],
       string_next_v: vec![PASS; VIRT_LEN],
 
-      empty_vertices: Vec::with_capacity(size * size),
+      empty_vertices: Vec::with_capacity(width * height),
       empty_v_index: vec![0; VIRT_LEN],
 
+      pattern_weights: None,
+      pattern_colors: vec![0; VIRT_LEN],
+      pattern_atari: vec![0; VIRT_LEN],
+      changed_vertices: Vec::with_capacity(VIRT_LEN),
+      atari_liberties: Vec::with_capacity(VIRT_LEN),
+
       num_black_stones: 0,
+      num_captured_black: 0,
+      num_captured_white: 0,
 
       ko_vertex: PASS,
 
+      hasher: rc::Rc::new(BoardHasher::new(width, height)),
+      hash: PosHash::None,
+      position_hashes: collections::HashSet::new(),
+      situation_hashes: collections::HashSet::new(),
+      superko_rule: SuperkoRule::Positional,
+      check_superko: true,
+      allow_suicide: false,
+      komi: 6.5,
+
       to_play: stone::BLACK,
       history: Vec::with_capacity(600),
+      undo_stack: Vec::with_capacity(600),
+      captured_stones: Vec::with_capacity(600),
     };
     game.reset();
     game
@@ -95,9 +263,15 @@ impl GoGame {
   pub fn reset(&mut self) {
     self.empty_vertices.clear();
     self.num_black_stones = 0;
+    self.num_captured_black = 0;
+    self.num_captured_white = 0;
     self.ko_vertex = PASS;
     self.to_play = stone::BLACK;
     self.history.clear();
+    self.undo_stack.clear();
+    self.captured_stones.clear();
+    self.position_hashes.clear();
+    self.situation_hashes.clear();
 
     for i in 0 .. (VIRT_LEN) as usize {
       self.strings[i].reset_border();
@@ -105,9 +279,9 @@ impl GoGame {
       self.string_next_v[i] = PASS;
     }
 
-    for col in 0 .. self.size {
-      for row in 0 .. self.size {
-        let v = GoGame::vertex(row as i16, col as i16);
+    for row in 0 .. self.height {
+      for col in 0 .. self.width {
+        let v = GoGame::vertex(col as i16, row as i16);
         self.board[v.as_index()] = stone::EMPTY;
         self.strings[v.as_index()].reset();
 
@@ -116,9 +290,9 @@ impl GoGame {
       }
     }
 
-    for col in 0 .. self.size {
-      for row in 0 .. self.size {
-        let v = Vertex::new(row as i16, col as i16);
+    for row in 0 .. self.height {
+      for col in 0 .. self.width {
+        let v = Vertex::new(col as i16, row as i16);
         for n in NEIGHBOURS[v.as_index()].iter() {
           if self.stone_at(*n) == stone::EMPTY {
             self.strings[v.as_index()].add_liberty(*n);
@@ -126,25 +300,75 @@ impl GoGame {
         }
       }
     }
+
+    if self.pattern_weights.is_some() {
+      self.reset_patterns();
+    }
+
+    self.hash = self.hasher.hash(self);
+    self.position_hashes.insert(self.hash);
+    self.situation_hashes.insert(self.hash ^ self.hasher.hash_to_play(self.to_play));
+  }
+
+  // Adds stones to the board without them counting as moves, e.g. for
+  // handicap. The resulting position becomes the start of the game: it can't be
+  // undone past and earlier positions no longer count for superko.
+  pub fn setup_stones(&mut self, stone: Stone, vertices: &[Vertex]) {
+    let to_play = self.to_play;
+    for v in vertices.iter() {
+      self.play(stone, *v);
+    }
+
+    self.to_play = to_play;
+    self.ko_vertex = PASS;
+    self.history.clear();
+    self.undo_stack.clear();
+    self.captured_stones.clear();
+    self.position_hashes.clear();
+    self.situation_hashes.clear();
+    self.position_hashes.insert(self.hash);
+    self.situation_hashes.insert(self.hash ^ self.hasher.hash_to_play(self.to_play));
+  }
+
+  pub fn is_on_board(&self, vertex: Vertex) -> bool {
+    let (x, y) = vertex.to_coords();
+    return vertex != PASS && x >= 0 && y >= 0 &&
+      (x as usize) < self.width && (y as usize) < self.height;
+  }
+
+  // Whether there are no stones on the board.
+  pub fn is_empty(&self) -> bool {
+    return self.empty_vertices.len() == self.width * self.height;
   }
 
   pub fn vertex(x: i16, y: i16) -> Vertex {
     Vertex::new(x, y)
   }
 
+  // Changes the stone at vertex and everything that is derived directly from
+  // the board.
+  fn update_board(&mut self, stone: Stone, vertex: Vertex) {
+    let old_stone = self.board[vertex.as_index()];
+    self.board[vertex.as_index()] = stone;
+    self.hash = self.hash ^ self.hasher.hash_for(vertex, old_stone) ^
+      self.hasher.hash_for(vertex, stone);
+
+    if self.pattern_weights.is_some() {
+      self.update_pattern_colors(stone, vertex);
+    }
+  }
+
   fn set_stone(&mut self, stone: Stone, vertex: Vertex) {
     let old_stone = self.board[vertex.as_index()];
     // Place new stone..
-    self.board[vertex.as_index()] = stone;
+    self.update_board(stone, vertex);
 
     // Update empty vertex list.
     if stone == stone::EMPTY {
       self.empty_v_index[vertex.as_index()] = self.empty_vertices.len();
       self.empty_vertices.push(vertex);
     } else {
-      if old_stone != stone::EMPTY {
-        println!("not empty!");
-      }
+      debug_assert!(old_stone == stone::EMPTY, "{} already has a stone", vertex);
       let i = self.empty_v_index[vertex.as_index()];
       {
         let last = self.empty_vertices.last().unwrap();
@@ -161,16 +385,101 @@ impl GoGame {
     }
   }
 
-  pub fn play(&mut self, stone: Stone, vertex: Vertex) -> bool {
-    if cfg!(debug) && !self.can_play(stone, vertex) {
-      return false;
+  // Reverts the last call to set_stone for vertex, restoring the exact order
+  // of the empty vertex list.
+  fn revert_stone(&mut self, old_stone: Stone, vertex: Vertex) {
+    let stone = self.board[vertex.as_index()];
+    self.update_board(old_stone, vertex);
+
+    if stone == stone::EMPTY {
+      self.empty_vertices.pop();
+    } else {
+      // Undo the swap_remove by moving the swapped vertex back to the end.
+      let i = self.empty_v_index[vertex.as_index()];
+      if i == self.empty_vertices.len() {
+        self.empty_vertices.push(vertex);
+      } else {
+        let moved = self.empty_vertices[i];
+        self.empty_v_index[moved.as_index()] = self.empty_vertices.len();
+        self.empty_vertices.push(moved);
+        self.empty_vertices[i] = vertex;
+      }
+    }
+
+    if stone == stone::BLACK {
+      self.num_black_stones -= 1;
+    } else if old_stone == stone::BLACK {
+      self.num_black_stones += 1;
+    }
+  }
+
+  // Plays stone at vertex if the rules allow it, otherwise explains why not
+  // and leaves the game unchanged.
+  pub fn try_play<'a>(&'a mut self, stone: Stone, vertex: Vertex)
+      -> Result<MoveReport<'a>, IllegalMove> {
+    try!(self.check_move(stone, vertex));
+    return Ok(self.play(stone, vertex));
+  }
+
+  // Why the rules don't allow to play stone at vertex, if they don't. Passing
+  // is always legal.
+  pub fn check_move(&self, stone: Stone, vertex: Vertex) -> Result<(), IllegalMove> {
+    if vertex == PASS {
+      return Ok(());
+    }
+    if !self.is_on_board(vertex) {
+      return Err(IllegalMove::OffBoard);
+    }
+    if self.stone_at(vertex) != stone::EMPTY {
+      return Err(IllegalMove::Occupied);
+    }
+    if vertex == self.ko_vertex {
+      return Err(IllegalMove::Ko);
     }
+    if !self.allow_suicide && self.is_suicide(stone, vertex) {
+      return Err(IllegalMove::Suicide);
+    }
+    if self.check_superko && self.repeats_position(stone, vertex) {
+      return Err(IllegalMove::Superko);
+    }
+    return Ok(());
+  }
+
+  // Plays stone at vertex, which must be a legal move, see is_legal and
+  // try_play.
+  pub fn play<'a>(&'a mut self, stone: Stone, vertex: Vertex) -> MoveReport<'a> {
+    let mut undo = Undo {
+      to_play: self.to_play,
+      ko_vertex: self.ko_vertex,
+      new_position: false,
+      new_situation: false,
+      vertex_string: String::new(),
+      vertex_next_v: PASS,
+      vertex_empty_index: 0,
+      joined_head: PASS,
+      joined_string: String::new(),
+      merged: [(PASS, PASS, String::new()); 3],
+      num_merged: 0,
+      captured: [(stone::EMPTY, PASS, String::new(), 0); 4],
+      num_captured: 0,
+    };
 
     self.to_play = stone.opponent();
     self.history.push((stone, vertex));
+    // Simple ko only forbids immediately taking back, passing resolves it.
+    self.ko_vertex = PASS;
 
     if vertex == PASS {
-      return true;
+      if self.check_superko {
+        self.record_position(&mut undo);
+      }
+      self.undo_stack.push(undo);
+      return MoveReport {
+        captured: &[],
+        merged: [PASS; 4],
+        num_merged: 0,
+        ko_vertex: PASS,
+      };
     }
 
     // Preparation for ko checking.
@@ -182,59 +491,285 @@ impl GoGame {
         played_in_enemy_eye = false;
       }
     }
-    self.ko_vertex = PASS;
 
-    self.join_groups_around(vertex, stone);
+    undo.vertex_string = self.strings[vertex.as_index()];
+    undo.vertex_next_v = self.string_next_v[vertex.as_index()];
+    undo.vertex_empty_index = self.empty_v_index[vertex.as_index()];
+    let num_captured_stones = self.captured_stones.len();
+    self.join_groups_around(vertex, stone, &mut undo);
+    let mut merged = [PASS; 4];
+    let mut num_merged = 0;
+    if undo.joined_head != PASS {
+      merged[0] = undo.joined_head;
+      for i in 0 .. undo.num_merged {
+        merged[i + 1] = undo.merged[i].1;
+      }
+      num_merged = undo.num_merged + 1;
+    }
     self.set_stone(stone, vertex);
     self.remove_liberty_from_neighbouring_groups(vertex);
-    self.capture_dead_groups(vertex, stone);
+    self.capture_dead_groups(vertex, stone, &mut undo);
 
     if played_in_enemy_eye && old_num_empty_vertices == self.empty_vertices.len() {
       self.ko_vertex = *self.empty_vertices.last().unwrap();
     }
 
-    return true;
+    // Only possible if suicide is allowed.
+    if self.dead(vertex) {
+      self.capture(vertex, &mut undo);
+    }
+
+    if self.check_superko {
+      self.record_position(&mut undo);
+    }
+    self.undo_stack.push(undo);
+
+    if self.pattern_weights.is_some() {
+      self.update_pattern_atari();
+    }
+    return MoveReport {
+      captured: &self.captured_stones[num_captured_stones ..],
+      merged: merged,
+      num_merged: num_merged,
+      ko_vertex: self.ko_vertex,
+    };
+  }
+
+  fn record_position(&mut self, undo: &mut Undo) {
+    undo.new_position = self.position_hashes.insert(self.hash);
+    undo.new_situation = self.situation_hashes.insert(
+      self.hash ^ self.hasher.hash_to_play(self.to_play));
   }
 
+  // Zobrist hash of the stones on the board, ignoring the player to move.
+  pub fn hash(&self) -> PosHash {
+    return self.hash;
+  }
+
+  // Takes back the last num_moves moves. Every move is reverted in time
+  // proportional to the number of stones it changed.
   pub fn undo(&mut self, num_moves: usize) -> bool {
     if num_moves > self.history.len() {
       return false;
     }
-    let history = self.history.clone();
-    self.reset();
-    // println!("{:?}", self);
-    for i in 0 .. (history.len() - num_moves) {
-      // println!("replaying {:} {:}", history[i].0, history[i].1);
-      self.play(history[i].0, history[i].1);
+    for _ in 0 .. num_moves {
+      self.undo_move();
     }
     return true;
   }
 
+  fn undo_move(&mut self) {
+    let (_, vertex) = self.history.pop().unwrap();
+    let undo = self.undo_stack.pop().unwrap();
+
+    if undo.new_position {
+      self.position_hashes.remove(&self.hash);
+    }
+    if undo.new_situation {
+      let hash = self.hash ^ self.hasher.hash_to_play(self.to_play);
+      self.situation_hashes.remove(&hash);
+    }
+    self.to_play = undo.to_play;
+    self.ko_vertex = undo.ko_vertex;
+
+    if vertex == PASS {
+      return;
+    }
+
+    // Revert everything in the opposite order of play.
+    for i in (0 .. undo.num_captured).rev() {
+      let (color, head, string, num_stones) = undo.captured[i];
+      self.restore_group(color, head, string, num_stones);
+    }
+    for n in NEIGHBOURS[vertex.as_index()].iter() {
+      if self.pattern_weights.is_some() {
+        self.remember_atari_liberty(*n);
+      }
+      self.strings[self.string_head[n.as_index()].as_index()].add_liberty(vertex);
+    }
+    // Captures since this move might have moved the vertex in the empty list.
+    self.empty_v_index[vertex.as_index()] = undo.vertex_empty_index;
+    self.revert_stone(stone::EMPTY, vertex);
+    self.split_groups_around(vertex, &undo);
+    if self.pattern_weights.is_some() {
+      self.update_pattern_atari();
+    }
+  }
+
+  // Reverts join_groups_around.
+  fn split_groups_around(&mut self, vertex: Vertex, undo: &Undo) {
+    let head = undo.joined_head;
+    if head != PASS {
+      // The new stone was inserted directly after the head.
+      self.string_next_v[head.as_index()] = self.string_next_v[vertex.as_index()];
+
+      for i in (0 .. undo.num_merged).rev() {
+        let (n, string_head, string) = undo.merged[i];
+        let tmp = self.string_next_v[head.as_index()];
+        self.string_next_v[head.as_index()] = self.string_next_v[n.as_index()];
+        self.string_next_v[n.as_index()] = tmp;
+
+        let mut cur = n;
+        loop {
+          self.string_head[cur.as_index()] = string_head;
+          cur = self.string_next_v[cur.as_index()];
+          if cur == n {
+            break;
+          }
+        }
+        self.strings[string_head.as_index()] = string;
+      }
+      self.strings[head.as_index()] = undo.joined_string;
+    }
+
+    self.strings[vertex.as_index()] = undo.vertex_string;
+    self.string_head[vertex.as_index()] = vertex;
+    self.string_next_v[vertex.as_index()] = undo.vertex_next_v;
+  }
+
+  // Reverts remove_group, putting back the last num_stones captured stones.
+  fn restore_group(&mut self, stone: Stone, head: Vertex, string: String,
+      num_stones: usize) {
+    let first = self.captured_stones.len() - num_stones;
+    for i in (first .. self.captured_stones.len()).rev() {
+      let cur = self.captured_stones[i];
+      for n in NEIGHBOURS[cur.as_index()].iter() {
+        let neighbour_string_head = self.string_head[n.as_index()];
+        if neighbour_string_head != head || self.stone_at(*n) == stone::EMPTY {
+          self.strings[neighbour_string_head.as_index()].remove_liberty(cur);
+        }
+      }
+
+      let next = if i + 1 == self.captured_stones.len() {
+        self.captured_stones[first]
+      } else {
+        self.captured_stones[i + 1]
+      };
+      self.string_head[cur.as_index()] = head;
+      self.string_next_v[cur.as_index()] = next;
+      self.revert_stone(stone, cur);
+    }
+    self.strings[head.as_index()] = string;
+    self.captured_stones.truncate(first);
+    if stone == stone::BLACK {
+      self.num_captured_black -= num_stones;
+    } else {
+      self.num_captured_white -= num_stones;
+    }
+  }
+
+  // Number of stones of this color that have been captured so far.
+  pub fn num_captured(&self, stone: Stone) -> usize {
+    if stone == stone::BLACK {
+      return self.num_captured_black;
+    }
+    return self.num_captured_white;
+  }
+
   fn remove_liberty_from_neighbouring_groups(&mut self, vertex: Vertex) {
     for n in NEIGHBOURS[vertex.as_index()].iter() {
       self.strings[self.string_head[n.as_index()].as_index()].remove_liberty(vertex);
     }
   }
 
-  fn capture_dead_groups(&mut self, vertex: Vertex, stone: Stone) {
+  fn capture_dead_groups(&mut self, vertex: Vertex, stone: Stone, undo: &mut Undo) {
     for n in NEIGHBOURS[vertex.as_index()].iter() {
       if self.stone_at(*n) == stone.opponent() && self.dead(*n) {
-        self.remove_group(*n);
+        self.capture(*n, undo);
       }
     }
   }
 
+  fn capture(&mut self, vertex: Vertex, undo: &mut Undo) {
+    let color = self.stone_at(vertex);
+    let head = self.string_head[vertex.as_index()];
+    let string = self.strings[head.as_index()];
+    let num_stones = self.remove_group(vertex);
+    undo.captured[undo.num_captured] = (color, head, string, num_stones);
+    undo.num_captured += 1;
+  }
+
   fn string(&self, vertex: Vertex) -> &String {
     return &self.strings[self.string_head[vertex.as_index()].as_index()];
   }
 
-  fn num_pseudo_liberties(&self, vertex: Vertex) -> u8 {
+  fn num_pseudo_liberties(&self, vertex: Vertex) -> u16 {
     return self.string(vertex).num_pseudo_liberties;
   }
 
+  // All stones of the string at vertex, nothing if there is no stone.
+  pub fn stones(&self, vertex: Vertex) -> Stones {
+    let s = self.stone_at(vertex);
+    if s == stone::BLACK || s == stone::WHITE {
+      return Stones::new(self, vertex);
+    }
+    return Stones::new(self, PASS);
+  }
+
+  // Exact liberties of the string at vertex, without duplicates. Much slower
+  // than the pseudo liberties, so only meant for tactics, not playouts.
+  pub fn liberties(&self, vertex: Vertex) -> Vec<Vertex> {
+    let mut liberties = vec![];
+    for v in self.stones(vertex) {
+      for n in NEIGHBOURS[v.as_index()].iter() {
+        if self.stone_at(*n) == stone::EMPTY && !liberties.contains(n) {
+          liberties.push(*n);
+        }
+      }
+    }
+    return liberties;
+  }
+
+  pub fn num_liberties(&self, vertex: Vertex) -> usize {
+    return self.liberties(vertex).len();
+  }
+
+  // One vertex of every string on the board, which identifies the string, see
+  // string_id.
+  pub fn strings(&self) -> Vec<Vertex> {
+    let mut strings = vec![];
+    for row in 0 .. self.height {
+      for col in 0 .. self.width {
+        let v = GoGame::vertex(col as i16, row as i16);
+        if self.string_id(v) == v {
+          strings.push(v);
+        }
+      }
+    }
+    return strings;
+  }
+
+  // The vertex identifying the string at vertex, the same for all its stones.
+  // PASS if there is no stone. Only valid until the string changes.
+  pub fn string_id(&self, vertex: Vertex) -> Vertex {
+    let s = self.stone_at(vertex);
+    if s == stone::BLACK || s == stone::WHITE {
+      return self.string_head[vertex.as_index()];
+    }
+    return PASS;
+  }
+
+  // Ids of the opponent strings touching the string at vertex, without
+  // duplicates.
+  pub fn adjacent_enemy_strings(&self, vertex: Vertex) -> Vec<Vertex> {
+    let opponent = self.stone_at(vertex).opponent();
+    let mut enemies = vec![];
+    for v in self.stones(vertex) {
+      for n in NEIGHBOURS[v.as_index()].iter() {
+        if self.stone_at(*n) == opponent {
+          let id = self.string_id(*n);
+          if !enemies.contains(&id) {
+            enemies.push(id);
+          }
+        }
+      }
+    }
+    return enemies;
+  }
+
   // Combines the groups around the newly placed stone at vertex. If no groups
   // are available for joining, the new stone is placed as it's one new group.
-  fn join_groups_around(&mut self, vertex: Vertex, stone: Stone) {
+  fn join_groups_around(&mut self, vertex: Vertex, stone: Stone, undo: &mut Undo) {
     let mut largest_group_head = PASS;
     let mut largest_group_size = 0;
     for n in NEIGHBOURS[vertex.as_index()].iter() {
@@ -251,11 +786,17 @@ impl GoGame {
       self.init_new_string(vertex);
       return;
     }
+    undo.joined_head = largest_group_head;
+    undo.joined_string = self.strings[largest_group_head.as_index()];
 
     for n in NEIGHBOURS[vertex.as_index()].iter() {
       if self.stone_at(*n) == stone {
         let string_head = self.string_head[n.as_index()];
         if string_head != largest_group_head {
+          undo.merged[undo.num_merged] = (*n, string_head,
+                                          self.strings[string_head.as_index()]);
+          undo.num_merged += 1;
+
           // Set all the stones in the smaller string to be part of the larger
           // string.
           let mut cur = *n;
@@ -315,11 +856,17 @@ impl GoGame {
     return self.string(vertex).num_pseudo_liberties == 0;
   }
 
-  fn remove_group(&mut self, vertex: Vertex) {
+  // Removes the string at vertex from the board and returns the number of
+  // captured stones, which are recorded in captured_stones for undo.
+  fn remove_group(&mut self, vertex: Vertex) -> usize {
     let mut cur = vertex;
     let string_head = self.string_head[vertex.as_index()];
+    let color = self.stone_at(vertex);
+    let mut num_stones = 0;
 
     loop {
+      self.captured_stones.push(cur);
+      num_stones += 1;
       self.set_stone(stone::EMPTY, cur);
       let next = self.string_next_v[cur.as_index()];
       self.init_new_string(cur);
@@ -327,6 +874,9 @@ impl GoGame {
       for n in NEIGHBOURS[cur.as_index()].iter() {
         let neighbour_string_head = self.string_head[n.as_index()];
         if neighbour_string_head != string_head || self.stone_at(*n) == stone::EMPTY {
+          if self.pattern_weights.is_some() {
+            self.remember_atari_liberty(*n);
+          }
           self.strings[neighbour_string_head.as_index()].add_liberty(cur);
         }
       }
@@ -336,79 +886,221 @@ impl GoGame {
         break;
       }
     }
+    if color == stone::BLACK {
+      self.num_captured_black += num_stones;
+    } else {
+      self.num_captured_white += num_stones;
+    }
+    return num_stones;
   }
 
   pub fn stone_at(&self, vertex: Vertex) -> Stone {
     return self.board[vertex.as_index()]
   }
 
+  // Whether the playout policy considers playing stone at vertex: the move has
+  // to be legal, must not fill one of our own eye-like points and must not
+  // break a seki.
   pub fn can_play(&self, stone: Stone, vertex: Vertex) -> bool {
-    if vertex == PASS {
-      return true;
-    }
+    return self.is_legal(stone, vertex) && !self.fills_own_eye(stone, vertex) &&
+      !self.is_seki_point(stone, vertex);
+  }
 
-    // Can't play if the vertex is not empty or would be ko.
-    if self.stone_at(vertex) != stone::EMPTY || vertex == self.ko_vertex {
-      return false;
+  // Whether the rules allow to play stone at vertex.
+  pub fn is_legal(&self, stone: Stone, vertex: Vertex) -> bool {
+    return self.check_move(stone, vertex).is_ok();
+  }
+
+  fn repeats_position(&self, stone: Stone, vertex: Vertex) -> bool {
+    let hash = self.hash_after(stone, vertex);
+    return match self.superko_rule {
+      SuperkoRule::Positional => self.position_hashes.contains(&hash),
+      SuperkoRule::Situational => self.situation_hashes.contains(
+        &(hash ^ self.hasher.hash_to_play(stone.opponent()))),
+    };
+  }
+
+  // Zobrist hash of the position after stone is played at vertex, without
+  // actually playing the move.
+  fn hash_after(&self, stone: Stone, vertex: Vertex) -> PosHash {
+    let mut hash = self.hash;
+    // With suicide all our neighbouring strings are removed, otherwise all
+    // enemy strings in atari.
+    let captured = if self.is_suicide(stone, vertex) {
+      stone
+    } else {
+      hash = hash ^ self.hasher.hash_for(vertex, stone::EMPTY) ^
+        self.hasher.hash_for(vertex, stone);
+      stone.opponent()
+    };
+
+    let mut captured_heads = [PASS; 4];
+    for (i, n) in NEIGHBOURS[vertex.as_index()].iter().enumerate() {
+      let head = self.string_head[n.as_index()];
+      if self.stone_at(*n) != captured || !self.string(*n).in_atari() ||
+          captured_heads.contains(&head) {
+        continue;
+      }
+      captured_heads[i] = head;
+
+      let mut cur = *n;
+      loop {
+        hash = hash ^ self.hasher.hash_for(cur, captured) ^
+          self.hasher.hash_for(cur, stone::EMPTY);
+        cur = self.string_next_v[cur.as_index()];
+        if cur == *n {
+          break;
+        }
+      }
     }
+    return hash;
+  }
 
+  // Whether the string of the stone placed at the empty vertex would have no
+  // liberties left after capturing.
+  fn is_suicide(&self, stone: Stone, vertex: Vertex) -> bool {
     // Can definitely play if the placed stone will have at least one direct
     // freedom (can't be ko).
     if self.string(vertex).num_pseudo_liberties > 0 {
-      return true;
+      return false;
     }
 
     // For all checks below, the newly placed stone is completely surrounded by
     // enemy and friendly stones.
 
-    // Don't allow to destroy eye-like points.
-    let mut surrounded_by_own = true;
+    // Allow to play if the placed stones connects to a group that still has at
+    // least one other liberty after connecting.
+    for n in NEIGHBOURS[vertex.as_index()].iter() {
+      if self.stone_at(*n) == stone && !self.string(*n).in_atari() {
+        return false;
+      }
+    }
+
+    // Allow to play if the placed stone will kill at least one group.
+    for n in NEIGHBOURS[vertex.as_index()].iter() {
+      if self.stone_at(*n) == stone.opponent() && self.string(*n).in_atari() {
+        return false;
+      }
+    }
+
+    // The stone would be dead or kill its own group.
+    return true;
+  }
+
+  // Whether vertex is an eye-like point of stone: surrounded by our own stones
+  // on all sides, with at most one enemy stone on the diagonals (none on the
+  // edge). Filling these is legal, but almost always a bad idea.
+  pub fn fills_own_eye(&self, stone: Stone, vertex: Vertex) -> bool {
+    if vertex == PASS || self.string(vertex).num_pseudo_liberties > 0 {
+      return false;
+    }
+
     let opponent = stone.opponent();
     for n in NEIGHBOURS[vertex.as_index()].iter() {
       let s = self.stone_at(*n);
       if s == opponent || s == stone::EMPTY {
-        surrounded_by_own = false;
-        break;
+        return false;
       }
     }
-    if surrounded_by_own {
-      let mut enemy_count = 0;
-      let mut border = 0;
-      for n in DIAG_NEIGHBOURS[vertex.as_index()].iter() {
-        let s = self.stone_at(*n);
-        if s == opponent {
-          enemy_count += 1;
-        } else if s == stone::BORDER {
-          border = 1;
-        }
+
+    let mut enemy_count = 0;
+    let mut border = 0;
+    for n in DIAG_NEIGHBOURS[vertex.as_index()].iter() {
+      let s = self.stone_at(*n);
+      if s == opponent {
+        enemy_count += 1;
+      } else if s == stone::BORDER {
+        border = 1;
       }
+    }
+    return enemy_count + border < 2;
+  }
 
-      if enemy_count + border < 2 {
-        // eye-like point
+  // Whether vertex is a liberty shared by black and white strings in seki, so
+  // that stone shouldn't play there: whoever plays there puts their own string
+  // into atari without capturing anything, and loses it. This covers the common
+  // shapes of two strings in a capturing race that have no eyes, or one eye
+  // each, besides the shared liberties.
+  pub fn is_seki_point(&self, stone: Stone, vertex: Vertex) -> bool {
+    // Empty vertices count their empty neighbours as pseudo liberties.
+    if vertex == PASS || self.stone_at(vertex) != stone::EMPTY ||
+        self.string(vertex).num_pseudo_liberties > 1 {
+      return false;
+    }
+
+    // Playouts ask for every candidate move, so first reject everything that
+    // doesn't touch an enemy string with two liberties, or that isn't a
+    // self-atari for us.
+    let opponent = stone.opponent();
+    if !NEIGHBOURS[vertex.as_index()].iter().any(|n| self.stone_at(*n) == opponent &&
+        self.in_race_at(*n, vertex)) || !self.is_self_atari(stone, vertex) {
+      return false;
+    }
+
+    // The liberty has to be shared with one of our strings, all strings around
+    // have to be in the race, and the opponent has to be in the same situation.
+    let mut touches_own = false;
+    for n in NEIGHBOURS[vertex.as_index()].iter() {
+      let s = self.stone_at(*n);
+      if s != stone::BLACK && s != stone::WHITE {
+        continue;
+      }
+      if !self.in_race_at(*n, vertex) {
         return false;
       }
+      touches_own = touches_own || s == stone;
     }
+    return touches_own && self.is_self_atari(opponent, vertex);
+  }
 
-    // Allow to play if the placed stones connects to a group that still has at
-    // least one other liberty after connecting.
+  // Whether the string at vertex might have exactly two liberties, one of them
+  // liberty. The pseudo liberties tell without reading the stones.
+  fn in_race_at(&self, vertex: Vertex, liberty: Vertex) -> bool {
+    // Every liberty is counted at most four times.
+    return self.num_pseudo_liberties(vertex) <= 8 &&
+      self.string(vertex).maybe_in_atari_without(liberty);
+  }
+
+  // Whether playing stone at vertex leaves the new string with at most one
+  // liberty and doesn't capture anything.
+  fn is_self_atari(&self, stone: Stone, vertex: Vertex) -> bool {
+    let mut liberty = PASS;
     for n in NEIGHBOURS[vertex.as_index()].iter() {
-      if self.stone_at(*n) == stone && !self.string(*n).in_atari() {
-        return true;
+      let s = self.stone_at(*n);
+      if s == stone.opponent() && self.string(*n).in_atari() {
+        return false;
+      }
+      if s == stone::EMPTY {
+        if liberty != PASS {
+          return false;
+        }
+        liberty = *n;
       }
     }
 
-    // Allow to play if the placed stone will kill at least one group.
     for n in NEIGHBOURS[vertex.as_index()].iter() {
-      if self.stone_at(*n) == stone.opponent() && self.string(*n).in_atari() {
-        return true;
+      if self.stone_at(*n) != stone {
+        continue;
+      }
+      for v in self.stones(*n) {
+        for m in NEIGHBOURS[v.as_index()].iter() {
+          if *m == vertex || *m == liberty || self.stone_at(*m) != stone::EMPTY {
+            continue;
+          }
+          if liberty != PASS {
+            return false;
+          }
+          liberty = *m;
+        }
       }
     }
-
-    // Don't allow to play if the stone would be dead or kill its own group.
-    return false;
+    return true;
   }
 
   pub fn random_move(&self, stone: Stone, rng: &mut rand::StdRng) -> Vertex {
+    if let Some(ref weights) = self.pattern_weights {
+      return self.weighted_random_move(stone, weights, rng);
+    }
     let num_empty = self.empty_vertices.len();
     if num_empty == 0 {
       return PASS;
@@ -431,13 +1123,30 @@ impl GoGame {
     }
   }
 
+  // All legal moves except pass.
   pub fn possible_moves(&self, stone: Stone) -> Vec<Vertex> {
+    return self.empty_vertices.iter().map(|v| v.clone())
+      .filter(|v| self.is_legal(stone, *v)).collect::<Vec<_>>();
+  }
+
+  // Legal moves that the playout policy would consider, see can_play.
+  pub fn candidate_moves(&self, stone: Stone) -> Vec<Vertex> {
     return self.empty_vertices.iter().map(|v| v.clone())
       .filter(|v| self.can_play(stone, *v)).collect::<Vec<_>>();
   }
 
+  // Komi doubled, to compare with doubled scores in integer.
+  pub fn double_komi(&self) -> i16 {
+    return (self.komi * 2.0) as i16;
+  }
+
+  // Fast area score for the end of random playouts, positive if black is
+  // ahead, without komi. Only single point eyes are counted as territory, so
+  // this is only exact once the board is filled up. Liberties shared in seki
+  // touch both colors and stay neutral. See tromp_taylor_score for arbitrary
+  // positions.
   pub fn chinese_score(&self) -> i16 {
-    let num_white_stones = (self.size * self.size) as i16 - self.num_black_stones - self.empty_vertices.len() as i16;
+    let num_white_stones = (self.width * self.height) as i16 - self.num_black_stones - self.empty_vertices.len() as i16;
 
     let mut eye_score = 0;
     for v in self.empty_vertices.iter() {
@@ -469,16 +1178,16 @@ impl GoGame {
 
 impl fmt::Display for GoGame {
   fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
-    let column_labels = "ABCDEFGHJKLMNOPORST";
+    let column_labels = vertex::COLUMN_LABELS;
     try!(write!(f, "\x1b[0;37m    "));
-    for col in 0 .. self.size {
+    for col in 0 .. self.width {
       try!(write!(f, " {}", column_labels.chars().nth(col).unwrap()));
     }
     try!(write!(f, "\n"));
 
-    for row in 0 .. self.size {
+    for row in 0 .. self.height {
       try!(write!(f, " {:2} \x1b[43m\x1b[1;37m ", row + 1));
-      for col in 0 .. self.size {
+      for col in 0 .. self.width {
         try!(match self.stone_at(GoGame::vertex(col as i16, row as i16)) {
           stone::BLACK => write!(f, "\x1b[30m\u{25CF}\x1b[37m "),
           stone::WHITE => write!(f, "\u{25CF} "),
@@ -489,7 +1198,7 @@ impl fmt::Display for GoGame {
     }
 
     try!(write!(f, "    "));
-    for col in 0 .. self.size {
+    for col in 0 .. self.width {
       try!(write!(f, " {}", column_labels.chars().nth(col).unwrap()));
     }
 
@@ -497,23 +1206,26 @@ impl fmt::Display for GoGame {
   }
 }
 
-
+// Plain text diagram with # for black, O for white and * for the point that
+// can't be played because of ko. Can be parsed back into a game, see diagram.rs.
 impl fmt::Debug for GoGame {
   fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
-    let column_labels = "ABCDEFGHJKLMNOPORST";
+    let column_labels = vertex::COLUMN_LABELS;
     try!(write!(f, "    "));
-    for col in 0 .. self.size {
+    for col in 0 .. self.width {
       try!(write!(f, "{}", column_labels.chars().nth(col).unwrap()));
     }
     try!(write!(f, "\n"));
 
-    let mut row = self.size - 1;
+    let mut row = self.height - 1;
     loop {
       try!(write!(f, " {:2} ", row + 1));
-      for col in 0 .. self.size {
-        try!(match self.stone_at(GoGame::vertex(col as i16, row as i16)) {
+      for col in 0 .. self.width {
+        let v = GoGame::vertex(col as i16, row as i16);
+        try!(match self.stone_at(v) {
           stone::BLACK => write!(f, "#"),
           stone::WHITE => write!(f, "O"),
+          _ if v == self.ko_vertex => write!(f, "*"),
           _ => write!(f, " ")
         });
       }
@@ -525,17 +1237,17 @@ impl fmt::Debug for GoGame {
     }
 
     try!(write!(f, "    "));
-    for col in 0 .. self.size {
+    for col in 0 .. self.width {
       try!(write!(f, "{}", column_labels.chars().nth(col).unwrap()));
     }
 
-    return write!(f, "");
+    return write!(f, "\n{} to play", self.to_play);
   }
 }
 
 impl cmp::PartialEq for GoGame {
   fn eq(&self, other: &GoGame) -> bool {
-    if self.size != other.size { 
+    if self.width != other.width || self.height != other.height {
       return false;
     }
 
@@ -543,9 +1255,9 @@ impl cmp::PartialEq for GoGame {
       return false;
     }
 
-    for col in 0 .. self.size {
-      for row in 0 .. self.size {
-        let v = Vertex::new(row as i16, col as i16);
+    for row in 0 .. self.height {
+      for col in 0 .. self.width {
+        let v = Vertex::new(col as i16, row as i16);
         if self.stone_at(v) != other.stone_at(v) {
           return false;
         }
@@ -0,0 +1,306 @@
+extern crate rand;
+
+use rand::Rng;
+use std::collections;
+use std::rc;
+
+use super::GoGame;
+use super::Stone;
+use super::Vertex;
+use super::PASS;
+use super::VIRT_SIZE;
+use super::NEIGHBOURS;
+use super::NUM_SYMMETRIES;
+use super::stone;
+
+// Positions of the 8 vertices around the center of a 3x3 pattern, as (x, y)
+// offsets, row by row. Seen from the vertex at position i, the center is at
+// position 7 - i.
+const POSITIONS: [(i16, i16); 8] = [
+  (-1, -1), (0, -1), (1, -1),
+  (-1, 0), (1, 0),
+  (-1, 1), (0, 1), (1, 1)];
+
+// The same positions as offsets on the virtual board.
+pub const OFFSETS: [i16; 8] = [
+  -(VIRT_SIZE as i16) - 1, -(VIRT_SIZE as i16), -(VIRT_SIZE as i16) + 1,
+  -1, 1,
+  VIRT_SIZE as i16 - 1, VIRT_SIZE as i16, VIRT_SIZE as i16 + 1];
+
+// Positions directly adjacent to the center, the only ones that can have an
+// atari flag.
+const ADJACENT: [usize; 4] = [1, 3, 4, 6];
+// For each of the NEIGHBOURS of a vertex, the atari bit that refers back to the
+// vertex in the pattern of the neighbour. Bit i is for position ADJACENT[i].
+const NEIGHBOUR_BITS: [usize; 4] = [2, 1, 3, 0];
+
+const COLOR_BITS: u32 = 0xffff;
+const BLACK_BITS: u32 = 0x5555;
+const WHITE_BITS: u32 = 0xaaaa;
+const ATARI_SHIFT: usize = 16;
+
+// Shape of the 3x3 square around an empty vertex. The lower 16 bits hold the
+// color of each of the 8 surrounding vertices (2 bits per position), bit
+// 16 + i is set if the string at position i is in atari.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, PartialOrd, Ord)]
+pub struct Pattern(u32);
+
+impl Pattern {
+  pub fn new(colors: u16, atari: [bool; 8]) -> Pattern {
+    let mut p = colors as u32;
+    for i in 0 .. 8 {
+      if atari[i] {
+        p |= 1 << (ATARI_SHIFT + i);
+      }
+    }
+    return Pattern(p);
+  }
+
+  pub fn color_at(self, position: usize) -> Stone {
+    return match (self.0 >> (2 * position)) & 3 {
+      0 => stone::EMPTY,
+      1 => stone::BLACK,
+      2 => stone::WHITE,
+      _ => stone::BORDER,
+    };
+  }
+
+  pub fn in_atari(self, position: usize) -> bool {
+    return self.0 & (1 << (ATARI_SHIFT + position)) != 0;
+  }
+
+  // The pattern rotated and/or mirrored by one of the 8 symmetries of the
+  // square. Symmetry 0 is the identity.
+  pub fn transform(self, symmetry: usize) -> Pattern {
+    let mut p = 0;
+    for i in 0 .. 8 {
+      let j = transform_position(i, symmetry);
+      p |= ((self.0 >> (2 * i)) & 3) << (2 * j);
+      p |= ((self.0 >> (ATARI_SHIFT + i)) & 1) << (ATARI_SHIFT + j);
+    }
+    return Pattern(p);
+  }
+
+  // The same pattern with black and white stones exchanged.
+  pub fn swap_colors(self) -> Pattern {
+    let colors = self.0 & COLOR_BITS;
+    let swapped = (colors & BLACK_BITS) << 1 | (colors & WHITE_BITS) >> 1;
+    return Pattern(self.0 & !COLOR_BITS | swapped);
+  }
+
+  // Representative of all patterns that are equivalent to this one for stone
+  // to play: the colors are swapped so that black is to play, then the
+  // smallest of the 8 symmetric patterns is chosen.
+  pub fn canonical(self, stone: Stone) -> Pattern {
+    let p = if stone == stone::WHITE { self.swap_colors() } else { self };
+    return (0 .. NUM_SYMMETRIES).map(|s| p.transform(s)).min().unwrap();
+  }
+}
+
+// Index of position after applying symmetry: bit 0 mirrors along the diagonal,
+// bit 1 mirrors horizontally and bit 2 vertically.
+fn transform_position(position: usize, symmetry: usize) -> usize {
+  let (mut x, mut y) = POSITIONS[position];
+  if symmetry & 1 != 0 {
+    let tmp = x;
+    x = y;
+    y = tmp;
+  }
+  if symmetry & 2 != 0 {
+    x = -x;
+  }
+  if symmetry & 4 != 0 {
+    y = -y;
+  }
+  return POSITIONS.iter().position(|p| *p == (x, y)).unwrap();
+}
+
+// Weights of 3x3 patterns for the playout policy. A weight applies to all
+// symmetric variations of the pattern, and to the pattern with swapped colors
+// when the other player is to play.
+pub struct PatternWeights {
+  // Weights with black to play, for all symmetric variations so that lookups
+  // don't need to transform the pattern.
+  weights: collections::HashMap<Pattern, f64>,
+  default_weight: f64,
+}
+
+impl PatternWeights {
+  pub fn new(default_weight: f64) -> PatternWeights {
+    return PatternWeights {
+      weights: collections::HashMap::new(),
+      default_weight: default_weight,
+    };
+  }
+
+  // Sets the weight of pattern with stone to play.
+  pub fn insert(&mut self, pattern: Pattern, stone: Stone, weight: f64) {
+    let p = if stone == stone::WHITE { pattern.swap_colors() } else { pattern };
+    for s in 0 .. NUM_SYMMETRIES {
+      self.weights.insert(p.transform(s), weight);
+    }
+  }
+
+  // Weight of pattern with stone to play, the default weight if it is unknown.
+  pub fn weight(&self, pattern: Pattern, stone: Stone) -> f64 {
+    let p = if stone == stone::WHITE { pattern.swap_colors() } else { pattern };
+    return match self.weights.get(&p) {
+      Some(w) => *w,
+      None => self.default_weight,
+    };
+  }
+}
+
+impl GoGame {
+  // Sets the weights for random_move, None for uniformly random moves. The
+  // patterns are computed from scratch when switching them on.
+  pub fn set_pattern_weights(&mut self, weights: Option<rc::Rc<PatternWeights>>) {
+    let was_tracking = self.pattern_weights.is_some();
+    self.pattern_weights = weights;
+    if !was_tracking && self.pattern_weights.is_some() {
+      self.reset_patterns();
+    }
+  }
+
+  // Random move that the playout policy considers, see can_play, picked with
+  // a probability proportional to the weight of its pattern. PASS if there is
+  // none.
+  pub fn weighted_random_move(&self, stone: Stone, weights: &PatternWeights,
+      rng: &mut rand::StdRng) -> Vertex {
+    let mut total = 0.0;
+    let mut chosen = PASS;
+    for v in self.empty_vertices.iter() {
+      if !self.can_play(stone, *v) {
+        continue;
+      }
+      let weight = weights.weight(self.pattern(*v), stone);
+      total += weight;
+      // Replacing the choice with probability weight / total leaves every
+      // vertex so far chosen with a probability proportional to its weight.
+      if weight > 0.0 && rng.gen::<f64>() * total < weight {
+        chosen = *v;
+      }
+    }
+    return chosen;
+  }
+
+  // The 3x3 pattern around the empty vertex, only maintained while there are
+  // pattern weights, see set_pattern_weights.
+  pub fn pattern(&self, vertex: Vertex) -> Pattern {
+    let mut atari = [false; 8];
+    for (i, position) in ADJACENT.iter().enumerate() {
+      atari[*position] = self.pattern_atari[vertex.as_index()] & (1 << i) != 0;
+    }
+    return Pattern::new(self.pattern_colors[vertex.as_index()], atari);
+  }
+
+  // Computes the patterns of all vertices from scratch.
+  pub fn reset_patterns(&mut self) {
+    self.changed_vertices.clear();
+    self.atari_liberties.clear();
+    for row in 0 .. self.height {
+      for col in 0 .. self.width {
+        let v = GoGame::vertex(col as i16, row as i16);
+        let mut colors = 0;
+        for (i, offset) in OFFSETS.iter().enumerate() {
+          colors |= (self.stone_at(Vertex(v.0 + offset)).as_index() as u16) << (2 * i);
+        }
+        self.pattern_colors[v.as_index()] = colors;
+        self.update_atari_bits(v);
+      }
+    }
+  }
+
+  // Updates the colors after stone was placed on or removed from vertex, which
+  // is at the opposite position in the patterns of its neighbours. The atari
+  // bits around vertex are updated at the end of the move, see
+  // update_pattern_atari.
+  pub fn update_pattern_colors(&mut self, stone: Stone, vertex: Vertex) {
+    for (i, offset) in OFFSETS.iter().enumerate() {
+      let n = (vertex.0 + offset) as usize;
+      let shift = 2 * (7 - i);
+      self.pattern_colors[n] = self.pattern_colors[n] & !(3 << shift) |
+        (stone.as_index() as u16) << shift;
+    }
+    self.changed_vertices.push(vertex);
+  }
+
+  // Must be called before the string at vertex gains a liberty: if it leaves
+  // atari, the bits at its old liberty have to be cleared.
+  pub fn remember_atari_liberty(&mut self, vertex: Vertex) {
+    let liberty = self.atari_liberty(vertex);
+    if liberty != PASS {
+      self.atari_liberties.push(liberty);
+    }
+  }
+
+  // Updates the atari bits at the end of a move or undo. Only strings touching
+  // the vertices whose stone changed can have changed their liberties. Those
+  // in atari now set the bits at their liberty, those that left atari were
+  // remembered before gaining a liberty.
+  pub fn update_pattern_atari(&mut self) {
+    for i in 0 .. self.changed_vertices.len() {
+      let v = self.changed_vertices[i];
+      let liberty = self.atari_liberty(v);
+      if liberty != PASS {
+        self.atari_liberties.push(liberty);
+      }
+      // Empty neighbours only need the bit pointing back to v, strings next to
+      // v are handled at their liberties.
+      for (j, n) in NEIGHBOURS[v.as_index()].iter().enumerate() {
+        let s = self.stone_at(*n);
+        if s == stone::EMPTY {
+          let bit = 1 << NEIGHBOUR_BITS[j];
+          if liberty != PASS {
+            self.pattern_atari[n.as_index()] |= bit;
+          } else {
+            self.pattern_atari[n.as_index()] &= !bit;
+          }
+        } else if s != stone::BORDER {
+          let liberty = self.atari_liberty(*n);
+          if liberty != PASS {
+            self.atari_liberties.push(liberty);
+          }
+        }
+      }
+      if self.stone_at(v) == stone::EMPTY {
+        self.update_atari_bits(v);
+      } else {
+        self.pattern_atari[v.as_index()] = 0;
+      }
+    }
+    for i in 0 .. self.atari_liberties.len() {
+      let v = self.atari_liberties[i];
+      self.update_atari_bits(v);
+    }
+    self.changed_vertices.clear();
+    self.atari_liberties.clear();
+  }
+
+  fn update_atari_bits(&mut self, vertex: Vertex) {
+    let mut bits = 0;
+    if self.stone_at(vertex) == stone::EMPTY {
+      for (i, position) in ADJACENT.iter().enumerate() {
+        if self.atari_liberty(Vertex(vertex.0 + OFFSETS[*position])) != PASS {
+          bits |= 1 << i;
+        }
+      }
+    }
+    self.pattern_atari[vertex.as_index()] = bits;
+  }
+
+  // The only liberty of the string at vertex if it is in atari, PASS if it
+  // isn't or there is no stone.
+  fn atari_liberty(&self, vertex: Vertex) -> Vertex {
+    let s = self.stone_at(vertex);
+    if s != stone::BLACK && s != stone::WHITE {
+      return PASS;
+    }
+    let string = self.string(vertex);
+    // Strings without liberties are about to be captured or restored.
+    if string.num_pseudo_liberties == 0 || !string.in_atari() {
+      return PASS;
+    }
+    return string.atari_liberty();
+  }
+}
@@ -0,0 +1,25 @@
+use super::GoGame;
+use super::SuperkoRule;
+
+impl GoGame {
+  // Sets superko_rule and allow_suicide for the named ruleset, e.g. "chinese",
+  // "new_zealand" or "Tromp-Taylor". Scoring is always by area, the rules only
+  // choose which moves are legal.
+  pub fn set_rules(&mut self, rules: &str) -> Result<(), String> {
+    let name = rules.trim().to_lowercase().replace("_", " ").replace("-", " ");
+    let (superko_rule, allow_suicide) = match &name[..] {
+      "chinese" => (SuperkoRule::Positional, false),
+      "tromp taylor" | "tt" => (SuperkoRule::Positional, true),
+      // Japanese rules have no superko, long cycles void the game instead. We
+      // can't void a game, and the search tree relies on positions never
+      // repeating, so positional superko is the closest we can get.
+      "japanese" => (SuperkoRule::Positional, false),
+      "aga" => (SuperkoRule::Situational, false),
+      "new zealand" | "nz" => (SuperkoRule::Situational, true),
+      _ => return Err(format!("unknown rules '{}'", rules)),
+    };
+    self.superko_rule = superko_rule;
+    self.allow_suicide = allow_suicide;
+    return Ok(());
+  }
+}
@@ -0,0 +1,111 @@
+use std::collections;
+
+use super::GoGame;
+use super::Vertex;
+use super::NEIGHBOURS;
+use super::VIRT_LEN;
+use super::stone;
+
+impl GoGame {
+  // Area score under Tromp-Taylor rules, positive if black is ahead, without
+  // komi. Each side gets its stones plus all empty regions that only its stones
+  // reach. Slower than chinese_score, but correct for any position.
+  pub fn tromp_taylor_score(&self) -> i16 {
+    return self.area_score(&collections::HashSet::new());
+  }
+
+  // Area score after removing the dead stones, positive if black is ahead,
+  // without komi.
+  pub fn area_score(&self, dead: &collections::HashSet<Vertex>) -> i16 {
+    let (black_territory, white_territory) = self.territory(dead);
+    let mut num_black_stones = self.num_black_stones;
+    let mut num_white_stones = (self.width * self.height) as i16 - self.num_black_stones -
+      self.empty_vertices.len() as i16;
+    for v in dead.iter() {
+      match self.stone_at(*v) {
+        stone::BLACK => num_black_stones -= 1,
+        stone::WHITE => num_white_stones -= 1,
+        _ => {},
+      }
+    }
+    return num_black_stones + black_territory - num_white_stones - white_territory;
+  }
+
+  // Score under territory rules (e.g. Japanese), positive if black is ahead,
+  // without komi. Each side gets its surrounded empty points and the stones it
+  // captured. Dead stones are taken off first and count as prisoners too.
+  pub fn japanese_score(&self, dead: &collections::HashSet<Vertex>) -> i16 {
+    let (black_territory, white_territory) = self.territory(dead);
+
+    let mut black_prisoners = self.num_captured(stone::WHITE) as i16;
+    let mut white_prisoners = self.num_captured(stone::BLACK) as i16;
+    for v in dead.iter() {
+      match self.stone_at(*v) {
+        stone::WHITE => black_prisoners += 1,
+        stone::BLACK => white_prisoners += 1,
+        _ => {},
+      }
+    }
+
+    return black_territory + black_prisoners - white_territory - white_prisoners;
+  }
+
+  // Number of points surrounded only by black and only by white stones, after
+  // removing the dead stones. Regions touching both colors are neutral.
+  fn territory(&self, dead: &collections::HashSet<Vertex>) -> (i16, i16) {
+    let mut black_territory = 0;
+    let mut white_territory = 0;
+    let mut visited = vec![false; VIRT_LEN];
+    let mut stack = vec![];
+
+    for row in 0 .. self.height {
+      for col in 0 .. self.width {
+        let v = GoGame::vertex(col as i16, row as i16);
+        if visited[v.as_index()] || !self.is_open(v, dead) {
+          continue;
+        }
+
+        // Flood fill the region of empty and dead points containing v.
+        let mut region_size = 0;
+        let mut touches_black = false;
+        let mut touches_white = false;
+        visited[v.as_index()] = true;
+        stack.push(v);
+        while let Some(cur) = stack.pop() {
+          region_size += 1;
+          for n in NEIGHBOURS[cur.as_index()].iter() {
+            if self.is_open(*n, dead) {
+              if !visited[n.as_index()] {
+                visited[n.as_index()] = true;
+                stack.push(*n);
+              }
+            } else {
+              match self.stone_at(*n) {
+                stone::BLACK => touches_black = true,
+                stone::WHITE => touches_white = true,
+                _ => {},
+              }
+            }
+          }
+        }
+
+        if touches_black && !touches_white {
+          black_territory += region_size;
+        } else if touches_white && !touches_black {
+          white_territory += region_size;
+        }
+      }
+    }
+
+    return (black_territory, white_territory);
+  }
+
+  // Whether vertex is on the board and has no living stone on it.
+  fn is_open(&self, vertex: Vertex, dead: &collections::HashSet<Vertex>) -> bool {
+    return match self.stone_at(vertex) {
+      stone::EMPTY => true,
+      stone::BORDER => false,
+      _ => dead.contains(&vertex),
+    };
+  }
+}
@@ -0,0 +1,142 @@
+extern crate rand;
+
+use std::cmp;
+
+use super::GoGame;
+use super::Stone;
+use super::Vertex;
+use super::PASS;
+use super::NEIGHBOURS;
+use super::VIRT_LEN;
+use super::stone;
+
+// Strings owned by their own color in at least this fraction of playouts are
+// alive, those owned by the opponent this often are dead. Random playouts are
+// noisy, even safe groups are regularly lost in a few of them.
+const OWNERSHIP_THRESHOLD: f64 = 0.6;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum StoneStatus {
+  Alive,
+  Dead,
+  // Alive with liberties shared with the opponent that stay empty.
+  Seki,
+}
+
+impl GoGame {
+  // Estimates the status of all strings at the end of the game by running
+  // num_playouts random games from the current position. Returns the stones
+  // of every string together with its status.
+  pub fn final_status(&self, num_playouts: u32, rng: &mut rand::StdRng)
+      -> Vec<(Vec<Vertex>, StoneStatus)> {
+    // How often each vertex was owned by black, white and nobody.
+    let mut num_black = vec![0; VIRT_LEN];
+    let mut num_white = vec![0; VIRT_LEN];
+
+    let mut game = self.clone();
+    // Superko is too expensive to check during random playouts.
+    game.check_superko = false;
+    let max_moves = cmp::max(700, 2 * self.width * self.height);
+
+    for i in 0 .. num_playouts {
+      // Both players passed already, so let them take turns starting the
+      // playout to not favour either side in fights like seki.
+      let mut color = if i % 2 == 0 { self.to_play } else { self.to_play.opponent() };
+      let mut num_consecutive_passes = 0;
+      let mut num_moves = 0;
+      while num_consecutive_passes < 2 && num_moves < max_moves {
+        let v = game.random_move(color, rng);
+        game.play(color, v);
+        num_consecutive_passes = if v == PASS { num_consecutive_passes + 1 } else { 0 };
+        num_moves += 1;
+        color = color.opponent();
+      }
+
+      for row in 0 .. self.height {
+        for col in 0 .. self.width {
+          let v = GoGame::vertex(col as i16, row as i16);
+          match game.owner(v) {
+            stone::BLACK => num_black[v.as_index()] += 1,
+            stone::WHITE => num_white[v.as_index()] += 1,
+            _ => {},
+          }
+        }
+      }
+
+      let num_moves = game.history.len() - self.history.len();
+      game.undo(num_moves);
+    }
+
+    let mut seen = vec![false; VIRT_LEN];
+    let mut strings = vec![];
+    for row in 0 .. self.height {
+      for col in 0 .. self.width {
+        let v = GoGame::vertex(col as i16, row as i16);
+        let color = self.stone_at(v);
+        if color != stone::BLACK && color != stone::WHITE || seen[v.as_index()] {
+          continue;
+        }
+
+        let mut stones = vec![];
+        let mut num_lost = 0;
+        let mut has_neutral_liberty = false;
+        let mut cur = v;
+        loop {
+          seen[cur.as_index()] = true;
+          stones.push(cur);
+          let other = if color == stone::BLACK { &num_white } else { &num_black };
+          num_lost += other[cur.as_index()];
+
+          for n in NEIGHBOURS[cur.as_index()].iter() {
+            if self.stone_at(*n) == stone::EMPTY {
+              let num_neutral = num_playouts - num_black[n.as_index()] - num_white[n.as_index()];
+              if num_neutral as f64 > num_playouts as f64 / 2.0 {
+                has_neutral_liberty = true;
+              }
+            }
+          }
+
+          cur = self.string_next_v[cur.as_index()];
+          if cur == v {
+            break;
+          }
+        }
+
+        let total = (stones.len() as u32 * num_playouts) as f64;
+        // Strings the playouts disagree about are alive, unless their
+        // liberties usually stay shared.
+        let status = if num_lost as f64 >= OWNERSHIP_THRESHOLD * total {
+          StoneStatus::Dead
+        } else if has_neutral_liberty {
+          StoneStatus::Seki
+        } else {
+          StoneStatus::Alive
+        };
+        strings.push((stones, status));
+      }
+    }
+    return strings;
+  }
+
+  // Owner of a vertex at the end of a playout: the color of the stone on it,
+  // or of all neighbours of an empty vertex. EMPTY if neutral.
+  pub fn owner(&self, vertex: Vertex) -> Stone {
+    let s = self.stone_at(vertex);
+    if s != stone::EMPTY {
+      return s;
+    }
+
+    let mut owner = stone::EMPTY;
+    for n in NEIGHBOURS[vertex.as_index()].iter() {
+      let s = self.stone_at(*n);
+      if s == stone::BORDER {
+        continue;
+      }
+      if s == stone::EMPTY || owner != stone::EMPTY && s != owner {
+        return stone::EMPTY;
+      }
+      owner = s;
+    }
+    return owner;
+  }
+}
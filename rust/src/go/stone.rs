@@ -18,6 +18,10 @@ impl Stone {
   pub fn opponent(self) -> Stone {
     return OPPONENT[self.0 as usize];
   }
+
+  pub fn as_index(self) -> usize {
+    return self.0 as usize;
+  }
 }
 
 impl fmt::Display for Stone {
@@ -1,17 +1,21 @@
+use std::cmp;
+
+use super::GoGame;
 use super::Stone;
 use super::stone;
 use super::Vertex;
+use super::PASS;
 
 // A string is a number of directly connected stones of the same color
 // (diagonal connections are not enough).
-#[derive(Clone)]
+#[derive(Copy, Clone)]
 pub struct String {
   color: Stone,
   pub num_stones: u16,
 
-  pub num_pseudo_liberties: u8,
-  liberty_vertex_sum: u16,
-  liberty_vertex_sum_squared: u32,
+  pub num_pseudo_liberties: u16,
+  liberty_vertex_sum: u32,
+  liberty_vertex_sum_squared: u64,
 }
 
 impl String {
@@ -43,8 +47,8 @@ impl String {
     // Need to have values big enough that they can never go below 0 even if
     // all liberties are removed.
     self.num_pseudo_liberties = 4;
-    self.liberty_vertex_sum = 32768;
-    self.liberty_vertex_sum_squared = 2147483648;
+    self.liberty_vertex_sum = 1 << 20;
+    self.liberty_vertex_sum_squared = 1 << 40;
   }
 
   pub fn merge(&mut self, other: &String) {
@@ -55,19 +59,75 @@ impl String {
   }
 
   pub fn in_atari(&self) -> bool {
-    return self.num_pseudo_liberties as u32 * self.liberty_vertex_sum_squared  ==
-      self.liberty_vertex_sum as u32 * self.liberty_vertex_sum as u32;
+    return self.num_pseudo_liberties as u64 * self.liberty_vertex_sum_squared  ==
+      self.liberty_vertex_sum as u64 * self.liberty_vertex_sum as u64;
+  }
+
+  // Whether the string might have exactly two liberties, one of them the
+  // liberty vertex. Without knowing how many stones are next to vertex, all
+  // possible counts are tried, so this can be true for other strings too.
+  pub fn maybe_in_atari_without(&self, vertex: Vertex) -> bool {
+    let v = vertex.0 as i64;
+    for n in 1 .. cmp::min(5, self.num_pseudo_liberties as i64) {
+      let num = self.num_pseudo_liberties as i64 - n;
+      let sum = self.liberty_vertex_sum as i64 - n * v;
+      let sum_squared = self.liberty_vertex_sum_squared as i64 - n * v * v;
+      if sum > 0 && num * sum_squared == sum * sum {
+        return true;
+      }
+    }
+    return false;
+  }
+
+  // The only liberty of a string in atari.
+  pub fn atari_liberty(&self) -> Vertex {
+    return Vertex((self.liberty_vertex_sum / self.num_pseudo_liberties as u32) as i16);
   }
 
   pub fn add_liberty(&mut self, vertex: Vertex) {
     self.num_pseudo_liberties += 1;
-    self.liberty_vertex_sum += vertex.0 as u16;
-    self.liberty_vertex_sum_squared += vertex.0 as u32 * vertex.0 as u32;
+    self.liberty_vertex_sum += vertex.0 as u32;
+    self.liberty_vertex_sum_squared += vertex.0 as u64 * vertex.0 as u64;
   }
 
   pub fn remove_liberty(&mut self, vertex: Vertex) {
     self.num_pseudo_liberties -= 1;
-    self.liberty_vertex_sum -= vertex.0 as u16;
-    self.liberty_vertex_sum_squared -= vertex.0 as u32 * vertex.0 as u32;
+    self.liberty_vertex_sum -= vertex.0 as u32;
+    self.liberty_vertex_sum_squared -= vertex.0 as u64 * vertex.0 as u64;
+  }
+}
+
+// Iterator over all stones of a string, following GoGame::string_next_v.
+pub struct Stones<'a> {
+  game: &'a GoGame,
+  start: Vertex,
+  next: Vertex,
+}
+
+impl<'a> Stones<'a> {
+  // Starts at vertex, which must have a stone on it or be PASS for an empty
+  // iterator.
+  pub fn new(game: &'a GoGame, vertex: Vertex) -> Stones<'a> {
+    Stones {
+      game: game,
+      start: vertex,
+      next: vertex,
+    }
+  }
+}
+
+impl<'a> Iterator for Stones<'a> {
+  type Item = Vertex;
+
+  fn next(&mut self) -> Option<Vertex> {
+    if self.next == PASS {
+      return None;
+    }
+    let cur = self.next;
+    self.next = self.game.string_next_v[cur.as_index()];
+    if self.next == self.start {
+      self.next = PASS;
+    }
+    return Some(cur);
   }
 }
@@ -0,0 +1,80 @@
+use super::GoGame;
+use super::PosHash;
+use super::stone;
+
+// Rotations and reflections of the board, see Vertex::transform.
+pub const NUM_SYMMETRIES: usize = 8;
+
+// The symmetry that undoes symmetry. Mirroring along the diagonal first swaps
+// the axes of the following reflections.
+pub fn inverse_symmetry(symmetry: usize) -> usize {
+  return match symmetry {
+    3 => 5,
+    5 => 3,
+    s => s,
+  };
+}
+
+impl GoGame {
+  // Whether symmetry maps the board onto itself, which is true for all of them
+  // on square boards. Rectangular boards can't be mirrored along the diagonal.
+  pub fn has_symmetry(&self, symmetry: usize) -> bool {
+    return symmetry & 1 == 0 || self.width == self.height;
+  }
+
+  pub fn symmetries(&self) -> Vec<usize> {
+    return (0 .. NUM_SYMMETRIES).filter(|s| self.has_symmetry(*s)).collect();
+  }
+
+  // Copy of the game with the stones and ko point transformed by symmetry. The
+  // resulting position is the start of the game, without history.
+  pub fn transform(&self, symmetry: usize) -> GoGame {
+    if !self.has_symmetry(symmetry) {
+      panic!("{}x{} board has no symmetry {}", self.width, self.height, symmetry);
+    }
+    let mut game = self.clone();
+    game.reset();
+    game.to_play = self.to_play;
+    for &color in [stone::BLACK, stone::WHITE].iter() {
+      let mut stones = vec![];
+      for row in 0 .. self.height {
+        for col in 0 .. self.width {
+          let v = GoGame::vertex(col as i16, row as i16);
+          if self.stone_at(v) == color {
+            stones.push(v.transform(symmetry, self.width, self.height));
+          }
+        }
+      }
+      game.setup_stones(color, &stones);
+    }
+    game.ko_vertex = self.ko_vertex.transform(symmetry, self.width, self.height);
+    return game;
+  }
+
+  // Zobrist hash that is the same for all symmetric variations of the
+  // position with the same player to move. Under situational superko the same
+  // stones with the other player to move allow different moves. Computed from
+  // scratch, so only cheap enough for the search tree, not for every move of a
+  // playout.
+  pub fn canonical_hash(&self) -> PosHash {
+    let hashes = self.hasher.symmetric_hashes(self);
+    return hashes[self.canonical_symmetry_of(&hashes)] ^
+      self.hasher.hash_to_play(self.to_play);
+  }
+
+  // The symmetry that transforms the position into its canonical variation,
+  // the one with the smallest hash.
+  pub fn canonical_symmetry(&self) -> usize {
+    return self.canonical_symmetry_of(&self.hasher.symmetric_hashes(self));
+  }
+
+  fn canonical_symmetry_of(&self, hashes: &[PosHash; NUM_SYMMETRIES]) -> usize {
+    let mut best = 0;
+    for s in 1 .. NUM_SYMMETRIES {
+      if self.has_symmetry(s) && hashes[s] < hashes[best] {
+        best = s;
+      }
+    }
+    return best;
+  }
+}
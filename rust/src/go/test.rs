@@ -1,4 +1,6 @@
 use super::GoGame;
+use super::SuperkoRule;
+use super::StoneStatus;
 use super::stone;
 use super::NEIGHBOURS;
 use super::DIAG_NEIGHBOURS;
@@ -6,9 +8,17 @@ use super::VIRT_LEN;
 use super::MAX_SIZE;
 use super::Vertex;
 use super::PASS;
+use super::NUM_SYMMETRIES;
+use super::symmetry;
+use super::PatternWeights;
+use super::IllegalMove;
 
 extern crate rand;
+use rand::Rng;
 use rand::SeedableRng;
+use std::cmp;
+use std::collections;
+use std::rc;
 
 #[test]
 fn stone_opponent() {
@@ -68,6 +78,65 @@ fn can_join_strings() {
   assert_eq!(6, game.num_pseudo_liberties(v));
 }
 
+#[test]
+fn list_strings() {
+  let mut game = GoGame::new(9);
+  game.setup_stones(stone::BLACK, &parse_vertices("B2 C2 B3 G7"));
+  game.setup_stones(stone::WHITE, &parse_vertices("B1 C1 D2 F7"));
+
+  let strings = game.strings();
+  assert_eq!(5, strings.len());
+  let mut colors = strings.iter().map(|v| game.stone_at(*v)).collect::<Vec<_>>();
+  colors.sort_by_key(|s| s.as_index());
+  assert_eq!(vec![stone::BLACK, stone::BLACK, stone::WHITE, stone::WHITE, stone::WHITE], colors);
+
+  // All stones of a string share its id.
+  let id = game.string_id(GoGame::vertex(1, 1));
+  assert!(strings.contains(&id));
+  for v in game.stones(id) {
+    assert_eq!(id, game.string_id(v));
+  }
+  assert_eq!(3, game.stones(id).count());
+  assert_eq!(PASS, game.string_id(GoGame::vertex(4, 4)));
+
+  let mut enemies = game.adjacent_enemy_strings(GoGame::vertex(1, 2));
+  enemies.sort();
+  let mut expected = vec![game.string_id(GoGame::vertex(1, 0)),
+    game.string_id(GoGame::vertex(3, 1))];
+  expected.sort();
+  assert_eq!(expected, enemies);
+  assert_eq!(vec![game.string_id(GoGame::vertex(6, 6))],
+    game.adjacent_enemy_strings(GoGame::vertex(5, 6)));
+  assert!(game.adjacent_enemy_strings(GoGame::vertex(4, 4)).is_empty());
+}
+
+#[test]
+fn exact_liberties() {
+  let mut game = GoGame::new(9);
+  game.play(stone::BLACK, GoGame::vertex(1, 1));
+  game.play(stone::BLACK, GoGame::vertex(2, 1));
+  game.play(stone::BLACK, GoGame::vertex(1, 2));
+  game.play(stone::WHITE, GoGame::vertex(1, 0));
+
+  // C3 is counted twice as pseudo liberty.
+  assert_eq!(7, game.num_pseudo_liberties(GoGame::vertex(1, 1)));
+  assert_eq!(6, game.num_liberties(GoGame::vertex(2, 1)));
+  let mut liberties = game.liberties(GoGame::vertex(1, 2));
+  liberties.sort();
+  let mut expected = parse_vertices("A2 A3 B4 C3 C1 D2");
+  expected.sort();
+  assert_eq!(expected, liberties);
+
+  let mut stones = game.stones(GoGame::vertex(2, 1)).collect::<Vec<_>>();
+  stones.sort();
+  let mut expected = parse_vertices("B2 C2 B3");
+  expected.sort();
+  assert_eq!(expected, stones);
+  assert_eq!(vec![GoGame::vertex(1, 0)], game.stones(GoGame::vertex(1, 0)).collect::<Vec<_>>());
+  assert_eq!(0, game.stones(GoGame::vertex(5, 5)).count());
+  assert_eq!(0, game.num_liberties(GoGame::vertex(5, 5)));
+}
+
 #[test]
 fn can_capture_single_stone() {
   let mut game = GoGame::new(9);
@@ -109,6 +178,55 @@ fn forbid_filling_real_eye() {
   assert_eq!(false, game.can_play(stone::BLACK, GoGame::vertex(1, 1)));
 }
 
+#[test]
+fn filling_real_eye_is_legal() {
+  let mut game = GoGame::new(9);
+  game.play(stone::BLACK, GoGame::vertex(0, 1));
+  game.play(stone::BLACK, GoGame::vertex(1, 0));
+  game.play(stone::BLACK, GoGame::vertex(1, 1));
+  let eye = GoGame::vertex(0, 0);
+  assert_eq!(true, game.fills_own_eye(stone::BLACK, eye));
+  assert_eq!(true, game.is_legal(stone::BLACK, eye));
+  assert!(game.possible_moves(stone::BLACK).contains(&eye));
+  assert!(!game.candidate_moves(stone::BLACK).contains(&eye));
+}
+
+fn multi_stone_suicide_position(allow_suicide: bool) -> GoGame {
+  let mut game = GoGame::new(9);
+  game.allow_suicide = allow_suicide;
+  game.play(stone::BLACK, GoGame::vertex(0, 0));
+  game.play(stone::WHITE, GoGame::vertex(1, 0));
+  game.play(stone::WHITE, GoGame::vertex(1, 1));
+  game.play(stone::WHITE, GoGame::vertex(0, 2));
+  return game;
+}
+
+#[test]
+fn forbid_suicide_by_default() {
+  let game = multi_stone_suicide_position(false);
+  assert_eq!(false, game.is_legal(stone::BLACK, GoGame::vertex(0, 1)));
+  assert!(!game.possible_moves(stone::BLACK).contains(&GoGame::vertex(0, 1)));
+}
+
+#[test]
+fn multi_stone_suicide() {
+  let mut game = multi_stone_suicide_position(true);
+  track_patterns(&mut game);
+  let before = game.clone();
+  assert_eq!(true, game.is_legal(stone::BLACK, GoGame::vertex(0, 1)));
+  game.play(stone::BLACK, GoGame::vertex(0, 1));
+  assert_eq!(stone::EMPTY, game.stone_at(GoGame::vertex(0, 0)));
+  assert_eq!(stone::EMPTY, game.stone_at(GoGame::vertex(0, 1)));
+  assert_eq!(5, game.num_pseudo_liberties(GoGame::vertex(1, 0)));
+  assert_eq!(3, game.num_pseudo_liberties(GoGame::vertex(0, 2)));
+  assert_eq!(0, game.num_black_stones);
+  assert_eq!(GoGame::new(9).empty_vertices.len() - 3, game.empty_vertices.len());
+  assert_eq!(game.hasher.hash(&game), game.hash());
+
+  assert!(game.undo(1));
+  assert_same_state(&before, &game);
+}
+
 #[test]
 fn forbid_filling_real_eyes_of_split_group() {
   let mut game = GoGame::new(9);
@@ -150,8 +268,8 @@ fn chinese_score_full_board() {
 #[test]
 fn chinese_score_also_count_eyes() {
   let mut game = GoGame::new(9);
-  for col in 0 .. MAX_SIZE as i16 {
-    for row in 0 .. MAX_SIZE as i16 {
+  for col in 0 .. 9 {
+    for row in 0 .. 9 {
       if col + row % 2 == 0 {
         game.play(stone::BLACK, GoGame::vertex(col, row));
       }
@@ -160,6 +278,387 @@ fn chinese_score_also_count_eyes() {
   assert_eq!(9*9, game.chinese_score());
 }
 
+// Black wall on the D column, white wall on the E column.
+fn split_board() -> GoGame {
+  let mut game = GoGame::new(9);
+  for row in 0 .. 9 {
+    game.play(stone::BLACK, GoGame::vertex(3, row));
+    game.play(stone::WHITE, GoGame::vertex(4, row));
+  }
+  return game;
+}
+
+#[test]
+fn count_captured_stones() {
+  let mut game = GoGame::new(9);
+  game.play(stone::WHITE, GoGame::vertex(0, 0));
+  game.play(stone::WHITE, GoGame::vertex(1, 0));
+  game.play(stone::BLACK, GoGame::vertex(2, 0));
+  game.play(stone::BLACK, GoGame::vertex(0, 1));
+  game.play(stone::BLACK, GoGame::vertex(1, 1));
+  assert_eq!(2, game.num_captured(stone::WHITE));
+  assert_eq!(0, game.num_captured(stone::BLACK));
+
+  game.undo(1);
+  assert_eq!(0, game.num_captured(stone::WHITE));
+}
+
+#[test]
+fn japanese_score_counts_territory() {
+  let game = split_board();
+  assert_eq!(27 - 36, game.japanese_score(&collections::HashSet::new()));
+}
+
+#[test]
+fn japanese_score_counts_prisoners() {
+  let mut game = split_board();
+  // Captured black stone.
+  game.play(stone::BLACK, GoGame::vertex(8, 8));
+  game.play(stone::WHITE, GoGame::vertex(7, 8));
+  game.play(stone::WHITE, GoGame::vertex(8, 7));
+  assert_eq!(1, game.num_captured(stone::BLACK));
+  let white_territory = 36 - 2;
+  // White invasion that can't live.
+  let invader = GoGame::vertex(1, 1);
+  game.play(stone::WHITE, invader);
+
+  // Unless marked dead, the invader spoils black's territory.
+  assert_eq!(-white_territory - 1, game.japanese_score(&collections::HashSet::new()));
+
+  let mut dead = collections::HashSet::new();
+  dead.insert(invader);
+  assert_eq!(27 + 1 - white_territory - 1, game.japanese_score(&dead));
+}
+
+// Black lives on the left with two eyes, white on the right with one, the
+// middle column is neutral apart from one black stone.
+fn small_board_with_eyes() -> GoGame {
+  return "
+        ABCDE
+      5 ## OO  5
+      4  # OO  4
+      3 ## O   3
+      2  # OO  2
+      1 ###OO  1
+        ABCDE".parse().unwrap();
+}
+
+#[test]
+fn chinese_score_counts_single_point_eyes() {
+  let game = small_board_with_eyes();
+  assert_eq!(11 - 10, game.chinese_score());
+  assert_eq!(11 - 10, game.tromp_taylor_score());
+}
+
+#[test]
+fn tromp_taylor_score_counts_large_regions() {
+  let mut game = split_board();
+  assert_eq!(9 + 27 - 9 - 36, game.tromp_taylor_score());
+
+  // Regions reached by both colors belong to neither.
+  game.play(stone::WHITE, GoGame::vertex(1, 1));
+  assert_eq!(9 - 10 - 36, game.tromp_taylor_score());
+}
+
+#[test]
+fn area_score_removes_dead_stones() {
+  let mut game = split_board();
+  let invader = GoGame::vertex(1, 1);
+  game.play(stone::WHITE, invader);
+  let mut dead = collections::HashSet::new();
+  dead.insert(invader);
+  assert_eq!(9 + 27 - 9 - 36, game.area_score(&dead));
+  assert_eq!(game.tromp_taylor_score(), game.area_score(&collections::HashSet::new()));
+
+  game.komi = 7.5;
+  assert_eq!(15, game.double_komi());
+  game.komi = -0.5;
+  assert_eq!(-1, game.double_komi());
+}
+
+fn status_of(strings: &Vec<(Vec<Vertex>, StoneStatus)>, vertex: Vertex) -> StoneStatus {
+  for &(ref stones, status) in strings.iter() {
+    if stones.contains(&vertex) {
+      return status;
+    }
+  }
+  panic!("no string at {}", vertex);
+}
+
+#[test]
+fn final_status_of_dead_invader() {
+  let mut rng = rand::StdRng::from_seed(&[42]);
+  let mut game = split_board();
+  game.play(stone::WHITE, GoGame::vertex(1, 1));
+  let strings = game.final_status(500, &mut rng);
+  assert_eq!(3, strings.len());
+  assert!(strings.iter().any(|s| s.0.len() == 9 && s.0.contains(&GoGame::vertex(3, 0))));
+  assert_eq!(StoneStatus::Dead, status_of(&strings, GoGame::vertex(1, 1)));
+  assert_eq!(StoneStatus::Alive, status_of(&strings, GoGame::vertex(3, 4)));
+  assert_eq!(StoneStatus::Alive, status_of(&strings, GoGame::vertex(4, 4)));
+  // Estimating doesn't change the game.
+  assert_eq!(19, game.history.len());
+}
+
+#[test]
+fn final_status_of_seki() {
+  let mut rng = rand::StdRng::from_seed(&[42]);
+  // Both strings share their only two liberties, whoever fills one dies.
+  let mut game = GoGame::new_rectangular(3, 2);
+  game.play(stone::BLACK, GoGame::vertex(0, 0));
+  game.play(stone::WHITE, GoGame::vertex(2, 0));
+  game.play(stone::BLACK, GoGame::vertex(0, 1));
+  game.play(stone::WHITE, GoGame::vertex(2, 1));
+  let strings = game.final_status(500, &mut rng);
+  assert_eq!(StoneStatus::Seki, status_of(&strings, GoGame::vertex(0, 0)));
+  assert_eq!(StoneStatus::Seki, status_of(&strings, GoGame::vertex(2, 0)));
+}
+
+#[test]
+fn final_status_of_undecided_fight() {
+  let mut rng = rand::StdRng::from_seed(&[42]);
+  // Either stone can end up alive, but they share no liberties.
+  let mut game = GoGame::new_rectangular(4, 4);
+  game.play(stone::BLACK, GoGame::vertex(0, 0));
+  game.play(stone::WHITE, GoGame::vertex(3, 3));
+  let strings = game.final_status(500, &mut rng);
+  assert_eq!(StoneStatus::Alive, status_of(&strings, GoGame::vertex(0, 0)));
+  assert_eq!(StoneStatus::Alive, status_of(&strings, GoGame::vertex(3, 3)));
+}
+
+#[test]
+fn playouts_keep_seki_without_eyes() {
+  let mut rng = rand::StdRng::from_seed(&[42]);
+  let mut game = GoGame::new_rectangular(3, 2);
+  game.play(stone::BLACK, GoGame::vertex(0, 0));
+  game.play(stone::WHITE, GoGame::vertex(2, 0));
+  game.play(stone::BLACK, GoGame::vertex(0, 1));
+  game.play(stone::WHITE, GoGame::vertex(2, 1));
+
+  for v in [GoGame::vertex(1, 0), GoGame::vertex(1, 1)].iter() {
+    assert!(game.is_seki_point(stone::BLACK, *v));
+    assert!(game.is_seki_point(stone::WHITE, *v));
+    // Breaking the seki is still legal, just bad.
+    assert!(game.is_legal(stone::BLACK, *v));
+    assert!(!game.can_play(stone::BLACK, *v));
+    assert!(!game.can_play(stone::WHITE, *v));
+  }
+  assert_eq!(PASS, game.random_move(stone::BLACK, &mut rng));
+  assert_eq!(PASS, game.random_move(stone::WHITE, &mut rng));
+  assert_eq!(0, game.chinese_score());
+}
+
+#[test]
+fn playouts_keep_seki_with_one_eye_each() {
+  let mut rng = rand::StdRng::from_seed(&[42]);
+  // C2 is the shared liberty, A2 and E2 are the eyes.
+  let mut game = GoGame::new_rectangular(5, 2);
+  game.setup_stones(stone::BLACK, &parse_vertices("A1 B1 C1 B2"));
+  game.setup_stones(stone::WHITE, &parse_vertices("D1 E1 D2"));
+
+  assert!(game.is_seki_point(stone::BLACK, GoGame::vertex(2, 1)));
+  assert!(game.is_seki_point(stone::WHITE, GoGame::vertex(2, 1)));
+  assert!(!game.is_seki_point(stone::WHITE, GoGame::vertex(0, 1)));
+  assert!(!game.is_seki_point(stone::BLACK, GoGame::vertex(4, 1)));
+  assert_eq!(PASS, game.random_move(stone::BLACK, &mut rng));
+  assert_eq!(PASS, game.random_move(stone::WHITE, &mut rng));
+  assert_eq!(1, game.chinese_score());
+  assert_eq!(1, game.tromp_taylor_score());
+}
+
+#[test]
+fn shared_liberty_without_race_is_no_seki() {
+  // C1 touches both colors, but black playing there keeps two liberties.
+  let mut game = GoGame::new(5);
+  game.setup_stones(stone::BLACK, &parse_vertices("B1 C2"));
+  game.setup_stones(stone::WHITE, &parse_vertices("D1 B2 D2"));
+  assert!(!game.is_seki_point(stone::BLACK, GoGame::vertex(2, 0)));
+  assert!(!game.is_seki_point(stone::WHITE, GoGame::vertex(2, 0)));
+  assert!(game.can_play(stone::BLACK, GoGame::vertex(2, 0)));
+}
+
+#[test]
+fn diagram_round_trip() {
+  let mut rng = rand::StdRng::from_seed(&[42]);
+  for &(width, height) in [(9, 9), (19, 19), (7, 4)].iter() {
+    let mut game = GoGame::new_rectangular(width, height);
+    game.check_superko = false;
+    let mut num_kos = 0;
+    for _ in 0 .. 300 {
+      let color = game.to_play;
+      let v = game.random_move(color, &mut rng);
+      game.play(color, v);
+      if game.ko_vertex != PASS {
+        num_kos += 1;
+      }
+
+      let diagram = format!("{:?}", game);
+      let parsed = diagram.parse::<GoGame>().unwrap();
+      assert_eq!(game, parsed);
+      assert_eq!(game.to_play, parsed.to_play);
+      assert_eq!(parsed.hasher.hash(&parsed), parsed.hash());
+      assert_eq!(diagram, format!("{:?}", parsed));
+    }
+    if width == 9 {
+      assert!(num_kos > 0);
+    }
+  }
+}
+
+#[test]
+fn parse_diagram_with_ko() {
+  let game = "
+        ABCDE
+      5        5
+      4  #O    4
+      3 #*#O   3
+      2  #O    2
+      1        1
+        ABCDE
+    white to play".parse::<GoGame>().unwrap();
+  assert_eq!(5, game.width);
+  assert_eq!(stone::WHITE, game.to_play);
+  assert_eq!(stone::BLACK, game.stone_at(GoGame::vertex(0, 2)));
+  assert_eq!(stone::WHITE, game.stone_at(GoGame::vertex(3, 2)));
+  assert!(!game.is_legal(stone::WHITE, GoGame::vertex(1, 2)));
+  assert!(game.history.is_empty());
+
+  // Dots work as empty points, black is to play by default.
+  let game = "
+      ABC
+    2 .O.  2
+    1 #..  1
+      ABC".parse::<GoGame>().unwrap();
+  assert_eq!((3, 2), (game.width, game.height));
+  assert_eq!(stone::BLACK, game.to_play);
+  assert_eq!(stone::WHITE, game.stone_at(GoGame::vertex(1, 1)));
+}
+
+#[test]
+fn parse_invalid_diagrams() {
+  assert!("".parse::<GoGame>().is_err());
+  assert!("  AB\n  1 ## 1\n  AC".parse::<GoGame>().is_err());
+  assert!("  AB\n  2 ## 2\n  AB".parse::<GoGame>().is_err());
+  assert!("  AB\n  1 #x 1\n  AB".parse::<GoGame>().is_err());
+  assert!("  AB\n  1 #  1\n  AB\nred to play".parse::<GoGame>().is_err());
+  // The white stone would have no liberties.
+  assert!("  ABC\n  2 #   2\n  1 O#  1\n  ABC".parse::<GoGame>().is_err());
+}
+
+#[test]
+fn vertex_symmetries() {
+  let v = GoGame::vertex(1, 2);
+  assert_eq!(GoGame::vertex(2, 1), v.transform(1, 9, 9));
+  assert_eq!(GoGame::vertex(7, 2), v.transform(2, 9, 9));
+  assert_eq!(GoGame::vertex(1, 4), v.transform(4, 7, 7));
+  assert_eq!(GoGame::vertex(5, 4), v.transform(6, 7, 7));
+  assert_eq!(PASS, PASS.transform(3, 9, 9));
+
+  let mut images = collections::HashSet::new();
+  for s in 0 .. NUM_SYMMETRIES {
+    let t = v.transform(s, 9, 9);
+    images.insert(t);
+    assert_eq!(v, t.transform(symmetry::inverse_symmetry(s), 9, 9));
+  }
+  assert_eq!(8, images.len());
+}
+
+#[test]
+fn symmetric_positions_share_canonical_hash() {
+  let mut rng = rand::StdRng::from_seed(&[42]);
+  for &(width, height) in [(9, 9), (7, 5)].iter() {
+    let mut game = GoGame::new_rectangular(width, height);
+    game.check_superko = false;
+    for _ in 0 .. 20 {
+      let color = game.to_play;
+      let v = game.random_move(color, &mut rng);
+      game.play(color, v);
+    }
+    assert_eq!(game.hash(), game.hasher.symmetric_hashes(&game)[0]);
+
+    let symmetries = game.symmetries();
+    assert_eq!(if width == height { 8 } else { 4 }, symmetries.len());
+    let mut hashes = collections::HashSet::new();
+    for s in symmetries {
+      let transformed = game.transform(s);
+      for v in game.empty_vertices.iter() {
+        assert_eq!(stone::EMPTY, transformed.stone_at(v.transform(s, width, height)));
+      }
+      assert_eq!(game.to_play, transformed.to_play);
+      assert_eq!(game.canonical_hash(), transformed.canonical_hash());
+      hashes.insert(transformed.hash());
+    }
+    // The position itself has no symmetry.
+    assert_eq!(game.symmetries().len(), hashes.len());
+
+    // Another move changes it.
+    let canonical_hash = game.canonical_hash();
+    let v = game.random_move(stone::BLACK, &mut rng);
+    game.play(stone::BLACK, v);
+    assert!(canonical_hash != game.canonical_hash());
+    game.undo(1);
+    assert_eq!(canonical_hash, game.canonical_hash());
+  }
+}
+
+#[test]
+fn canonical_hash_includes_player_to_move() {
+  let mut game = GoGame::new(9);
+  game.play(stone::BLACK, vertex("C3"));
+  game.play(stone::WHITE, PASS);
+  let black_to_play = game.canonical_hash();
+  game.to_play = stone::WHITE;
+  assert!(black_to_play != game.canonical_hash());
+  assert_eq!(game.canonical_hash(), game.transform(2).canonical_hash());
+}
+
+#[test]
+fn move_reports() {
+  let mut game = "
+      ABCDE
+    5       5
+    4  #O   4
+    3 #O O  3
+    2  #O   2
+    1  #    1
+      ABCDE".parse::<GoGame>().unwrap();
+  let id = game.string_id(vertex("B1"));
+  let report = game.play(stone::BLACK, vertex("A1"));
+  assert!(report.captured.is_empty());
+  assert_eq!(&[id], &report.merged[.. report.num_merged]);
+  assert_eq!(PASS, report.ko_vertex);
+
+  // Taking C3 is ko.
+  let report = game.play(stone::BLACK, vertex("C3"));
+  assert_eq!(&[vertex("B3")], report.captured);
+  assert_eq!(0, report.num_merged);
+  assert_eq!(vertex("B3"), report.ko_vertex);
+
+  // Connecting three strings.
+  let mut game = "
+      ABC
+    3      3
+    2 # #  2
+    1  #   1
+      ABC".parse::<GoGame>().unwrap();
+  let ids = [vertex("A2"), vertex("C2"), vertex("B1")].iter()
+    .map(|v| game.string_id(*v)).collect::<collections::HashSet<_>>();
+  let report = game.play(stone::BLACK, vertex("B2"));
+  assert_eq!(3, report.num_merged);
+  assert_eq!(ids, report.merged[.. 3].iter().map(|v| *v).collect());
+  assert_eq!(PASS, game.play(stone::WHITE, PASS).ko_vertex);
+}
+
+#[test]
+fn move_report_for_suicide() {
+  let mut game = multi_stone_suicide_position(true);
+  let report = game.play(stone::BLACK, GoGame::vertex(0, 1));
+  let mut captured = report.captured.to_vec();
+  captured.sort();
+  assert_eq!(vec![GoGame::vertex(0, 0), GoGame::vertex(0, 1)], captured);
+  assert_eq!(PASS, report.ko_vertex);
+}
+
 #[test]
 fn reset_game() {
   let mut game = GoGame::new(9);
@@ -181,6 +680,103 @@ fn parse_vertex() {
   assert_eq!(PASS, format!("{}", PASS).parse::<Vertex>().unwrap());
 }
 
+#[test]
+fn largest_board() {
+  assert_eq!(GoGame::vertex(24, 24), "Z25".parse::<Vertex>().unwrap());
+  assert_eq!(GoGame::vertex(7, 20), "H21".parse::<Vertex>().unwrap());
+
+  let mut game = GoGame::new(MAX_SIZE as usize);
+  game.play(stone::WHITE, GoGame::vertex(24, 24));
+  game.play(stone::BLACK, GoGame::vertex(23, 24));
+  game.play(stone::BLACK, GoGame::vertex(24, 23));
+  assert_eq!(stone::EMPTY, game.stone_at(GoGame::vertex(24, 24)));
+  // The corner is suicide for white.
+  assert_eq!(25 * 25 - 3, game.possible_moves(stone::WHITE).len());
+}
+
+#[test]
+fn rectangular_board() {
+  let mut game = GoGame::new_rectangular(7, 9);
+  assert_eq!(7 * 9, game.possible_moves(stone::BLACK).len());
+  assert_eq!(stone::EMPTY, game.stone_at(GoGame::vertex(6, 8)));
+  assert_eq!(stone::BORDER, game.stone_at(GoGame::vertex(7, 0)));
+  assert_eq!(stone::BORDER, game.stone_at(GoGame::vertex(0, 9)));
+
+  game.play(stone::WHITE, GoGame::vertex(6, 8));
+  game.play(stone::BLACK, GoGame::vertex(5, 8));
+  game.play(stone::BLACK, GoGame::vertex(6, 7));
+  assert_eq!(stone::EMPTY, game.stone_at(GoGame::vertex(6, 8)));
+  assert_eq!(2, game.num_black_stones);
+  assert_eq!(game.hasher.hash(&game), game.hash());
+  assert!(GoGame::new_rectangular(9, 7) != GoGame::new_rectangular(7, 9));
+
+  let diagram = format!("{:?}", game);
+  let lines = diagram.lines().collect::<Vec<_>>();
+  assert_eq!(9 + 3, lines.len());
+  assert_eq!("    ABCDEFG", lines[0]);
+  assert_eq!("  9      #   9", lines[1]);
+  assert_eq!("white to play", lines[11]);
+}
+
+fn vertex(vertex: &str) -> Vertex {
+  return vertex.parse::<Vertex>().unwrap();
+}
+
+fn parse_vertices(vertices: &str) -> Vec<Vertex> {
+  return vertices.split(" ").map(|v| v.parse::<Vertex>().unwrap()).collect();
+}
+
+#[test]
+fn fixed_handicap_layouts() {
+  let game = GoGame::new(19);
+  assert_eq!(parse_vertices("D4 Q16"), game.fixed_handicap_vertices(2).unwrap());
+  assert_eq!(parse_vertices("D4 Q16 D16"), game.fixed_handicap_vertices(3).unwrap());
+  assert_eq!(parse_vertices("D4 Q16 D16 Q4 K10"), game.fixed_handicap_vertices(5).unwrap());
+  assert_eq!(parse_vertices("D4 Q16 D16 Q4 D10 Q10 K4 K16 K10"),
+    game.fixed_handicap_vertices(9).unwrap());
+  assert!(game.fixed_handicap_vertices(1).is_err());
+  assert!(game.fixed_handicap_vertices(10).is_err());
+
+  let game = GoGame::new(9);
+  assert_eq!(parse_vertices("C3 G7 C7 G3 C5 G5"), game.fixed_handicap_vertices(6).unwrap());
+
+  assert_eq!(4, GoGame::new(7).max_fixed_handicap());
+  assert_eq!(4, GoGame::new(10).max_fixed_handicap());
+  assert_eq!(0, GoGame::new(5).max_fixed_handicap());
+}
+
+#[test]
+fn free_handicap_spreads_stones() {
+  let game = GoGame::new(9);
+  let vertices = game.free_handicap_vertices(12).unwrap();
+  assert_eq!(12, vertices.len());
+  assert_eq!(game.fixed_handicap_vertices(9).unwrap(), vertices[.. 9].to_vec());
+  for (i, v) in vertices.iter().enumerate() {
+    assert_eq!(stone::EMPTY, game.stone_at(*v));
+    assert!(!vertices[i + 1 ..].contains(v));
+  }
+}
+
+#[test]
+fn place_handicap() {
+  let mut game = GoGame::new(9);
+  let vertices = game.fixed_handicap_vertices(4).unwrap();
+  game.place_handicap(&vertices);
+  assert_eq!(stone::WHITE, game.to_play);
+  assert!(game.history.is_empty());
+  assert_eq!(4, game.num_black_stones);
+  for v in vertices.iter() {
+    assert_eq!(stone::BLACK, game.stone_at(*v));
+    assert!(!game.is_legal(stone::WHITE, *v));
+  }
+
+  game.play(stone::WHITE, GoGame::vertex(4, 4));
+  assert!(game.undo(1));
+  assert!(!game.undo(1));
+  assert_eq!(4, game.num_black_stones);
+  assert_eq!(game.hasher.hash(&game), game.hash());
+}
+
 #[test]
 fn can_undo() {
   let mut game = GoGame::new(9);
@@ -196,4 +792,347 @@ fn can_undo() {
   game.play(stone::WHITE, GoGame::vertex(0, 1));
   second_game.play(stone::WHITE, GoGame::vertex(0, 1));
   assert_eq!(format!("{:?}", second_game), format!("{:?}", game));
-}
\ No newline at end of file
+}
+
+#[test]
+fn forbid_positional_superko() {
+  let mut game = GoGame::new(9);
+  game.play(stone::BLACK, GoGame::vertex(0, 1));
+  game.play(stone::BLACK, GoGame::vertex(1, 0));
+  game.play(stone::BLACK, GoGame::vertex(1, 2));
+  game.play(stone::WHITE, GoGame::vertex(2, 0));
+  game.play(stone::WHITE, GoGame::vertex(2, 2));
+  game.play(stone::WHITE, GoGame::vertex(3, 1));
+  game.play(stone::WHITE, GoGame::vertex(1, 1));
+
+  // Black takes the ko, white can't take back immediately.
+  game.play(stone::BLACK, GoGame::vertex(2, 1));
+  assert_eq!(stone::EMPTY, game.stone_at(GoGame::vertex(1, 1)));
+  assert_eq!(false, game.can_play(stone::WHITE, GoGame::vertex(1, 1)));
+
+  // Passing clears simple ko, but taking back would still repeat the position.
+  game.play(stone::WHITE, PASS);
+  game.play(stone::BLACK, PASS);
+  assert_eq!(false, game.can_play(stone::WHITE, GoGame::vertex(1, 1)));
+
+  game.check_superko = false;
+  assert_eq!(true, game.can_play(stone::WHITE, GoGame::vertex(1, 1)));
+}
+
+// Three kos on the left side of the board, in columns 0 - 3 of rows 0 - 2,
+// 3 - 5 and 6 - 8. The first two are held by white, the last one by black.
+fn triple_ko(rule: SuperkoRule) -> GoGame {
+  let mut game = GoGame::new(9);
+  game.superko_rule = rule;
+  for row in vec![1, 4, 7] {
+    game.play(stone::BLACK, GoGame::vertex(0, row));
+    game.play(stone::BLACK, GoGame::vertex(1, row - 1));
+    game.play(stone::BLACK, GoGame::vertex(1, row + 1));
+    game.play(stone::WHITE, GoGame::vertex(3, row));
+    game.play(stone::WHITE, GoGame::vertex(2, row - 1));
+    game.play(stone::WHITE, GoGame::vertex(2, row + 1));
+  }
+  game.play(stone::BLACK, GoGame::vertex(2, 7));
+  game.play(stone::WHITE, GoGame::vertex(1, 1));
+  game.play(stone::WHITE, GoGame::vertex(1, 4));
+  return game;
+}
+
+#[test]
+fn forbid_triple_ko_cycle() {
+  for rule in vec![SuperkoRule::Positional, SuperkoRule::Situational] {
+    let mut game = triple_ko(rule);
+    let cycle = vec![GoGame::vertex(2, 1), GoGame::vertex(1, 7),
+                     GoGame::vertex(2, 4), GoGame::vertex(1, 1),
+                     GoGame::vertex(2, 7)];
+    for v in cycle {
+      let c = game.to_play;
+      assert!(game.can_play(c, v), format!("{:?} {}", rule, v));
+      game.play(c, v);
+    }
+    // Taking the last ko would repeat the starting position with black to
+    // play.
+    assert_eq!(false, game.can_play(stone::WHITE, GoGame::vertex(1, 4)));
+  }
+}
+
+// Black stone on the first line, with the vertices on either side empty. If
+// black throws in at A1, white captures two stones at C1 and black can take
+// one back at B1.
+fn send_two_return_one(rule: SuperkoRule) -> GoGame {
+  let mut game = GoGame::new(9);
+  game.superko_rule = rule;
+  game.play(stone::BLACK, GoGame::vertex(1, 0));
+  game.play(stone::BLACK, GoGame::vertex(3, 0));
+  game.play(stone::BLACK, GoGame::vertex(2, 1));
+  game.play(stone::WHITE, GoGame::vertex(0, 1));
+  game.play(stone::WHITE, GoGame::vertex(1, 1));
+  game.play(stone::BLACK, GoGame::vertex(0, 0));
+  game.play(stone::WHITE, GoGame::vertex(2, 0));
+  assert_eq!(stone::EMPTY, game.stone_at(GoGame::vertex(0, 0)));
+  assert_eq!(stone::EMPTY, game.stone_at(GoGame::vertex(1, 0)));
+  return game;
+}
+
+#[test]
+fn positional_superko_forbids_round_trip() {
+  let game = send_two_return_one(SuperkoRule::Positional);
+  // Same stones as before the throw in, but now with white to play.
+  assert_eq!(false, game.can_play(stone::BLACK, GoGame::vertex(1, 0)));
+}
+
+#[test]
+fn situational_superko_allows_round_trip() {
+  let mut game = send_two_return_one(SuperkoRule::Situational);
+  assert_eq!(true, game.can_play(stone::BLACK, GoGame::vertex(1, 0)));
+  game.play(stone::BLACK, GoGame::vertex(1, 0));
+  assert_eq!(stone::EMPTY, game.stone_at(GoGame::vertex(2, 0)));
+
+  // After white passes, throwing in again would repeat the position after the
+  // first throw in, with white to play.
+  game.play(stone::WHITE, PASS);
+  assert_eq!(false, game.can_play(stone::BLACK, GoGame::vertex(0, 0)));
+}
+
+#[test]
+fn incremental_hash_matches_full_hash() {
+  let mut rng = rand::StdRng::from_seed(&[42]);
+  let mut game = GoGame::new(9);
+  game.check_superko = false;
+  for _ in 0 .. 20 {
+    game.reset();
+    let mut num_consecutive_passes = 0;
+    while num_consecutive_passes < 2 {
+      let c = game.to_play;
+      let v = game.random_move(c, &mut rng);
+      game.play(c, v);
+      num_consecutive_passes = if v == PASS { num_consecutive_passes + 1 } else { 0 };
+      assert_eq!(game.hasher.hash(&game), game.hash());
+    }
+  }
+}
+
+// Uniform pattern weights, only to maintain the patterns.
+fn track_patterns(game: &mut GoGame) {
+  game.set_pattern_weights(Some(rc::Rc::new(PatternWeights::new(1.0))));
+}
+
+// Checks the incrementally updated pattern of every empty vertex against the
+// board.
+fn assert_patterns_match_board(game: &GoGame) {
+  let positions = [(-1, -1), (0, -1), (1, -1), (-1, 0), (1, 0), (-1, 1), (0, 1), (1, 1)];
+  for v in game.empty_vertices.iter() {
+    let pattern = game.pattern(*v);
+    let (x, y) = v.to_coords();
+    for (i, &(dx, dy)) in positions.iter().enumerate() {
+      let n = GoGame::vertex(x + dx, y + dy);
+      assert_eq!(game.stone_at(n), pattern.color_at(i));
+      let s = game.stone_at(n);
+      let in_atari = dx * dy == 0 && (s == stone::BLACK || s == stone::WHITE) &&
+        game.num_liberties(n) == 1;
+      assert_eq!(in_atari, pattern.in_atari(i));
+    }
+  }
+}
+
+#[test]
+fn patterns_follow_moves_and_undo() {
+  let mut rng = rand::StdRng::from_seed(&[42]);
+  let mut game = GoGame::new(9);
+  game.check_superko = false;
+  track_patterns(&mut game);
+  for _ in 0 .. 5 {
+    game.reset();
+    assert_patterns_match_board(&game);
+    let mut color = stone::BLACK;
+    for _ in 0 .. 150 {
+      let v = game.random_move(color, &mut rng);
+      game.play(color, v);
+      color = color.opponent();
+      assert_patterns_match_board(&game);
+    }
+    game.undo(75);
+    assert_patterns_match_board(&game);
+  }
+}
+
+#[test]
+fn pattern_at_the_edge() {
+  let mut game = GoGame::new(9);
+  track_patterns(&mut game);
+  game.play(stone::BLACK, GoGame::vertex(1, 0));
+  let pattern = game.pattern(GoGame::vertex(0, 0));
+  for i in [0, 1, 2, 3, 5].iter() {
+    assert_eq!(stone::BORDER, pattern.color_at(*i));
+  }
+  assert_eq!(stone::BLACK, pattern.color_at(4));
+  assert_eq!(stone::EMPTY, pattern.color_at(6));
+  assert_eq!(stone::EMPTY, pattern.color_at(7));
+}
+
+// Plays the stones around the center of a 9x9 board after mirroring them with
+// the given symmetry, see Pattern::transform.
+fn symmetric_shape(black: &[(i16, i16)], white: &[(i16, i16)], symmetry: usize) -> GoGame {
+  let transform = |&(mut x, mut y): &(i16, i16)| {
+    if symmetry & 1 != 0 {
+      let tmp = x;
+      x = y;
+      y = tmp;
+    }
+    if symmetry & 2 != 0 {
+      x = -x;
+    }
+    if symmetry & 4 != 0 {
+      y = -y;
+    }
+    GoGame::vertex(4 + x, 4 + y)
+  };
+  let mut game = GoGame::new(9);
+  track_patterns(&mut game);
+  game.setup_stones(stone::BLACK, &black.iter().map(&transform).collect::<Vec<_>>());
+  game.setup_stones(stone::WHITE, &white.iter().map(&transform).collect::<Vec<_>>());
+  return game;
+}
+
+#[test]
+fn pattern_weights_are_symmetric() {
+  // Some shape without symmetries, the last white stone is in atari right of
+  // the center.
+  let black = [(1, -1), (2, 0), (1, 1), (-1, -1)];
+  let white = [(0, -1), (-1, 1), (1, 0)];
+  let center = GoGame::vertex(4, 4);
+  let pattern = symmetric_shape(&black, &white, 0).pattern(center);
+  assert!(pattern.in_atari(4));
+
+  let mut weights = PatternWeights::new(1.0);
+  weights.insert(pattern, stone::BLACK, 5.0);
+  let mut variations = collections::HashSet::new();
+  for symmetry in 0 .. 8 {
+    let p = symmetric_shape(&black, &white, symmetry).pattern(center);
+    variations.insert(p);
+    assert_eq!(pattern.canonical(stone::BLACK), p.canonical(stone::BLACK));
+    assert_eq!(5.0, weights.weight(p, stone::BLACK));
+    assert_eq!(1.0, weights.weight(p, stone::WHITE));
+
+    // The same shape with swapped colors, for the other player to move.
+    let swapped = symmetric_shape(&white, &black, symmetry).pattern(center);
+    assert_eq!(p.swap_colors(), swapped);
+    assert_eq!(pattern.canonical(stone::BLACK), swapped.canonical(stone::WHITE));
+    assert_eq!(5.0, weights.weight(swapped, stone::WHITE));
+    assert_eq!(1.0, weights.weight(swapped, stone::BLACK));
+  }
+  assert_eq!(8, variations.len());
+
+  // Without the stone in atari it's a different pattern.
+  let other = symmetric_shape(&black, &white[.. 2], 0).pattern(center);
+  assert!(other.canonical(stone::BLACK) != pattern.canonical(stone::BLACK));
+  assert_eq!(1.0, weights.weight(other, stone::BLACK));
+}
+
+#[test]
+fn random_move_follows_pattern_weights() {
+  let mut rng = rand::StdRng::from_seed(&[42]);
+  let mut game = GoGame::new(9);
+  game.setup_stones(stone::BLACK, &parse_vertices("E5"));
+  // Only the points right next to the stone, in any direction, are played.
+  let mut weights = PatternWeights::new(0.0);
+  track_patterns(&mut game);
+  weights.insert(game.pattern("F5".parse::<Vertex>().unwrap()), stone::WHITE, 1.0);
+  game.set_pattern_weights(Some(rc::Rc::new(weights)));
+  let mut moves = collections::HashSet::new();
+  for _ in 0 .. 100 {
+    moves.insert(game.random_move(stone::WHITE, &mut rng));
+  }
+  let mut expected = collections::HashSet::new();
+  expected.extend(parse_vertices("D5 F5 E4 E6"));
+  assert_eq!(expected, moves);
+
+  // Black has no pattern with weight.
+  assert_eq!(PASS, game.random_move(stone::BLACK, &mut rng));
+}
+
+// Checks everything that influences how the game continues, not only the
+// stones on the board.
+fn assert_same_state(expected: &GoGame, got: &GoGame) {
+  assert_eq!(expected, got);
+  assert_eq!(expected.to_play, got.to_play);
+  assert_eq!(expected.history, got.history);
+  assert_eq!(expected.hash(), got.hash());
+  assert_eq!(expected.position_hashes, got.position_hashes);
+  assert_eq!(expected.empty_vertices, got.empty_vertices);
+  assert_eq!(expected.num_black_stones, got.num_black_stones);
+  assert_eq!(expected.num_captured(stone::BLACK), got.num_captured(stone::BLACK));
+  assert_eq!(expected.num_captured(stone::WHITE), got.num_captured(stone::WHITE));
+  for col in 0 .. expected.width as i16 {
+    for row in 0 .. expected.height as i16 {
+      let v = GoGame::vertex(col, row);
+      assert_eq!(expected.num_pseudo_liberties(v), got.num_pseudo_liberties(v));
+      assert_eq!(expected.string(v).num_stones, got.string(v).num_stones);
+      assert_eq!(expected.string(v).in_atari(), got.string(v).in_atari());
+      assert_eq!(expected.pattern(v), got.pattern(v));
+      if expected.stone_at(v) != stone::EMPTY {
+        assert_eq!(expected.string_head[v.as_index()], got.string_head[v.as_index()]);
+        assert_eq!(expected.string_next_v[v.as_index()], got.string_next_v[v.as_index()]);
+        assert_eq!(got.string(v).in_atari(), got.num_liberties(v) == 1);
+      }
+    }
+  }
+}
+
+#[test]
+fn undo_matches_replay() {
+  let mut rng = rand::StdRng::from_seed(&[42]);
+  let mut game = GoGame::new(9);
+  track_patterns(&mut game);
+  for _ in 0 .. 50 {
+    game.reset();
+    let mut num_consecutive_passes = 0;
+    while num_consecutive_passes < 2 && game.history.len() < 300 {
+      let c = game.to_play;
+      let v = game.random_move(c, &mut rng);
+      game.play(c, v);
+      num_consecutive_passes = if v == PASS { num_consecutive_passes + 1 } else { 0 };
+    }
+
+    while !game.history.is_empty() {
+      let num_moves = rng.gen_range(1, cmp::min(game.history.len(), 20) + 1);
+      assert!(game.undo(num_moves));
+
+      let mut replayed = game.clone();
+      replayed.reset();
+      for &(c, v) in game.history.iter() {
+        replayed.play(c, v);
+      }
+      assert_same_state(&replayed, &game);
+    }
+  }
+}
+
+#[test]
+fn try_play_explains_illegal_moves() {
+  let mut game = multi_stone_suicide_position(false);
+  let board = format!("{:?}", game);
+  let num_moves = game.history.len();
+  assert_eq!(Err(IllegalMove::Suicide), game.try_play(stone::BLACK, GoGame::vertex(0, 1)));
+  assert_eq!(Err(IllegalMove::Occupied), game.try_play(stone::BLACK, GoGame::vertex(1, 1)));
+  assert_eq!(Err(IllegalMove::OffBoard), game.try_play(stone::BLACK, GoGame::vertex(9, 0)));
+  assert_eq!(Err(IllegalMove::OffBoard), game.try_play(stone::BLACK, GoGame::vertex(-1, 0)));
+  assert_eq!(board, format!("{:?}", game));
+  assert_eq!(num_moves, game.history.len());
+  assert!(game.try_play(stone::BLACK, PASS).is_ok());
+
+  // The ko from forbid_positional_superko, first simple ko then superko.
+  let mut game = GoGame::new(9);
+  game.setup_stones(stone::BLACK, &[GoGame::vertex(0, 1), GoGame::vertex(1, 0),
+    GoGame::vertex(1, 2)]);
+  game.setup_stones(stone::WHITE, &[GoGame::vertex(2, 0), GoGame::vertex(2, 2),
+    GoGame::vertex(3, 1), GoGame::vertex(1, 1)]);
+  assert_eq!(&[GoGame::vertex(1, 1)],
+    game.try_play(stone::BLACK, GoGame::vertex(2, 1)).unwrap().captured);
+  assert_eq!(Err(IllegalMove::Ko), game.try_play(stone::WHITE, GoGame::vertex(1, 1)));
+  game.play(stone::WHITE, PASS);
+  game.play(stone::BLACK, PASS);
+  assert_eq!(Err(IllegalMove::Superko), game.try_play(stone::WHITE, GoGame::vertex(1, 1)));
+  assert_eq!(stone::EMPTY, game.stone_at(GoGame::vertex(1, 1)));
+  assert_eq!("superko", format!("{}", IllegalMove::Superko));
+}
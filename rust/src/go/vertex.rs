@@ -9,6 +9,9 @@ pub struct Vertex(pub i16);
 
 pub const PASS: Vertex = Vertex(-1);
 
+// GTP column names, skipping I to avoid confusion with J.
+pub const COLUMN_LABELS: &'static str = "ABCDEFGHJKLMNOPQRSTUVWXYZ";
+
 impl Vertex {
   pub fn new(x: i16, y: i16) -> Vertex {
     Vertex(x + 1 + (y + 1) * VIRT_SIZE as i16)
@@ -21,6 +24,28 @@ impl Vertex {
   pub fn as_index(self) -> usize {
     return self.0 as usize;
   }
+
+  // The vertex after applying one of the 8 symmetries to a width x height
+  // board: bit 0 mirrors along the diagonal, which is only possible on square
+  // boards, bit 1 mirrors horizontally and bit 2 vertically.
+  pub fn transform(self, symmetry: usize, width: usize, height: usize) -> Vertex {
+    if self == PASS {
+      return PASS;
+    }
+    let (mut x, mut y) = self.to_coords();
+    if symmetry & 1 != 0 {
+      let tmp = x;
+      x = y;
+      y = tmp;
+    }
+    if symmetry & 2 != 0 {
+      x = width as i16 - 1 - x;
+    }
+    if symmetry & 4 != 0 {
+      y = height as i16 - 1 - y;
+    }
+    return Vertex::new(x, y);
+  }
 }
 
 impl fmt::Display for Vertex {
@@ -29,7 +54,8 @@ impl fmt::Display for Vertex {
       return write!(f, "PASS");
     }
     let (x, y) = self.to_coords();
-    let column_labels = "aABCDEFGHJKLMNOPQRSTu";
+    // Lowercase letters mark the border to the left and right of the board.
+    let column_labels = format!("a{}z", COLUMN_LABELS);
     try!(write!(f, "{}", column_labels.chars().nth((x + 1) as usize).unwrap()));
     return write!(f, "{}", y + 1);
   }
@@ -51,11 +77,10 @@ impl str::FromStr for Vertex {
     if s.len() < 2 || s.len() > 3 {
       return Err("expected Vertex of format A1".to_string());
     }
-    let column_labels = "ABCDEFGHJKLMNOPQRST";
     let col_char = s.chars().next().unwrap();
-    let col = match column_labels.find(|c| c == col_char) {
+    let col = match COLUMN_LABELS.find(|c| c == col_char) {
       Some(i) => i,
-      None => return Err("column must be A - T".to_string()),
+      None => return Err("column must be A - Z".to_string()),
     };
     let row = (s[1..]).parse::<i16>();
     if row.is_err() {
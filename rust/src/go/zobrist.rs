@@ -0,0 +1,138 @@
+extern crate rand;
+
+use std::ops::BitXor;
+use std::ops::Rem;
+use rand::Rng;
+
+use super::VIRT_LEN;
+use super::VIRT_SIZE;
+use super::GoGame;
+use super::Vertex;
+use super::Stone;
+use super::NUM_SYMMETRIES;
+use super::stone;
+
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, PartialOrd, Ord)]
+pub struct PosHash(u64);
+
+impl BitXor for PosHash {
+	type Output = PosHash;
+
+	fn bitxor(self, rhs: PosHash) -> PosHash {
+		PosHash(self.0 ^ rhs.0)
+	}
+}
+
+impl PosHash {
+  pub const None: PosHash = PosHash(0);
+
+  pub fn new(h: u64) -> PosHash {
+    PosHash(h)
+  }
+
+  pub fn as_index(self) -> usize {
+    self.0 as usize
+  }
+}
+
+pub struct BoardHasher {
+  // Zobrist hashing for tracking super-ko and debugging normal ko checking.
+  vertex_hashes: Vec<PosHash>,
+  // Added to positions with white to play, for situational super-ko.
+  white_to_play: PosHash,
+  // The vertex hashes of the vertex after applying each of the symmetries, to
+  // hash the mirrored and rotated variations of a position.
+  symmetric_vertex_hashes: Vec<PosHash>,
+}
+
+impl BoardHasher {
+  pub fn new(width: usize, height: usize) -> BoardHasher {
+    let mut rng = rand::thread_rng();
+    let mut vertex_hashes =  vec![PosHash(0); 4 * VIRT_LEN];
+    let size = VIRT_SIZE as usize;
+    for col in 0 .. size {
+      for row in 0 .. size {
+        vertex_hashes[0 * VIRT_LEN + col + row * size] = PosHash(rng.gen()); // EMPTY
+        vertex_hashes[1 * VIRT_LEN + col + row * size] = PosHash(rng.gen()); // BLACK
+        vertex_hashes[2 * VIRT_LEN + col + row * size] = PosHash(rng.gen()); // WHITE
+        vertex_hashes[3 * VIRT_LEN + col + row * size] = PosHash(rng.gen()); // BORDER
+      }
+    }
+
+    let mut symmetric_vertex_hashes = vec![PosHash(0); NUM_SYMMETRIES * 4 * VIRT_LEN];
+    for symmetry in 0 .. NUM_SYMMETRIES {
+      // Mirroring along the diagonal is impossible for rectangular boards.
+      let valid = symmetry & 1 == 0 || width == height;
+      for row in 0 .. height {
+        for col in 0 .. width {
+          let v = Vertex::new(col as i16, row as i16);
+          let t = if valid { v.transform(symmetry, width, height) } else { v };
+          for stone in 0 .. 4 {
+            symmetric_vertex_hashes[(symmetry * 4 + stone) * VIRT_LEN + v.as_index()] =
+              vertex_hashes[stone * VIRT_LEN + t.as_index()];
+          }
+        }
+      }
+    }
+
+    return BoardHasher{
+      vertex_hashes: vertex_hashes,
+      white_to_play: PosHash(rng.gen()),
+      symmetric_vertex_hashes: symmetric_vertex_hashes,
+    };
+  }
+
+  // Hashes of the position after applying each symmetry, see
+  // Vertex::transform. The first one is the identity and equal to hash.
+  pub fn symmetric_hashes(&self, game: &GoGame) -> [PosHash; NUM_SYMMETRIES] {
+    let mut hashes = [PosHash(0); NUM_SYMMETRIES];
+    for row in 0 .. game.height {
+      for col in 0 .. game.width {
+        let v = Vertex::new(col as i16, row as i16);
+        let stone = game.stone_at(v);
+        for s in 0 .. NUM_SYMMETRIES {
+          hashes[s] = hashes[s] ^ self.hash_for_symmetry(s, v, stone);
+        }
+      }
+    }
+    return hashes;
+  }
+
+  // Hash of stone at vertex after applying symmetry to the board.
+  pub fn hash_for_symmetry(&self, symmetry: usize, vertex: Vertex, stone: Stone) -> PosHash {
+    return self.symmetric_vertex_hashes[
+      (symmetry * 4 + stone.as_index()) * VIRT_LEN + vertex.as_index()];
+  }
+
+  pub fn hash(&self, game: &GoGame) -> PosHash {
+    let mut hash = PosHash(0);
+    for row in 0 .. game.height {
+      for col in 0 .. game.width {
+        let v = Vertex::new(col as i16, row as i16);
+        hash = hash ^ self.hash_for(v, game.stone_at(v));
+      }
+    }
+    return hash;
+  }
+
+  // Hash to combine with the board hash to also distinguish positions by the
+  // player to move.
+  pub fn hash_to_play(&self, stone: Stone) -> PosHash {
+    if stone == stone::WHITE {
+      return self.white_to_play;
+    }
+    return PosHash(0);
+  }
+
+  // Calculates zobrist hash for a vertex. Used for super-ko detection.
+  pub fn hash_for(&self, vertex: Vertex, stone: Stone) -> PosHash {
+    let offset = match stone {
+      stone::EMPTY => 0,
+      stone::BLACK => 1,
+      stone::WHITE => 2,
+      stone::BORDER => 3,
+      _ => panic!("unknown stone"),
+    };
+    return self.vertex_hashes[offset * VIRT_LEN + vertex.as_index()];
+  }
+}
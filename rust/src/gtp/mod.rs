@@ -5,11 +5,23 @@ use mcts::Controller;
 use std::collections;
 use go::Stone;
 use go::Vertex;
+use go::PASS;
+use go::stone;
+use go::StoneStatus;
+use go::MAX_SIZE;
+use sgf;
+use std::fs;
+use std::io::Read;
 extern crate time;
 
+#[cfg(test)]
+mod test;
+
 pub struct Engine {
   game: GoGame,
-  controller: Controller,
+  // Players, result and such of the game loaded with loadsgf.
+  game_info: sgf::GameInfo,
+  controller: Controller<GoGame>,
   rng: rand::StdRng,
   commands: collections::HashMap<String, fn(&mut Engine, Vec<&str>) -> Result<String, String> >,
   analyze_commands: collections::HashMap<String, fn(&mut Engine, Vec<&str>) -> Result<String, String> >,
@@ -23,17 +35,29 @@ impl Engine {
     commands.insert("quit".to_string(), Engine::quit);
     commands.insert("name".to_string(), Engine::name);
     commands.insert("boardsize".to_string(), Engine::boardsize);
+    commands.insert("rectangular_boardsize".to_string(), Engine::rectangular_boardsize);
     commands.insert("version".to_string(), Engine::version);
     commands.insert("clear_board".to_string(), Engine::clear_board);
     commands.insert("list_commands".to_string(), Engine::list_commands);
     commands.insert("genmove".to_string(), Engine::genmove);
     commands.insert("play".to_string(), Engine::play);
+    commands.insert("fixed_handicap".to_string(), Engine::fixed_handicap);
+    commands.insert("place_free_handicap".to_string(), Engine::place_free_handicap);
+    commands.insert("set_free_handicap".to_string(), Engine::set_free_handicap);
+    commands.insert("final_status_list".to_string(), Engine::final_status_list);
+    commands.insert("komi".to_string(), Engine::komi);
+    commands.insert("kgs-rules".to_string(), Engine::kgs_rules);
+    commands.insert("final_score".to_string(), Engine::final_score);
+    commands.insert("loadsgf".to_string(), Engine::loadsgf);
+    commands.insert("printsgf".to_string(), Engine::printsgf);
     commands.insert("gogui-analyze_commands".to_string(), Engine::gogui_analyze_commands);
 
     analyze_commands.insert("move_values".to_string(), Engine::move_values);
+    analyze_commands.insert("ownership".to_string(), Engine::ownership);
 
     Engine {
       game: GoGame::new(9),
+      game_info: sgf::GameInfo::new(),
       controller: Controller::new(),
       rng: rng,
       commands: commands,
@@ -65,11 +89,63 @@ impl Engine {
     if args.len() != 3 {
       return Err("expected: play C V".to_string());
     }
-    self.game.play(try!(args[1].parse::<Stone>()), try!(args[2].parse::<Vertex>()));
+    let color = try!(args[1].parse::<Stone>());
+    let vertex = try!(args[2].parse::<Vertex>());
+    if let Err(e) = self.game.try_play(color, vertex) {
+      // Off-board vertices can't be displayed, log them as given.
+      info!("rejected {} {}: {}", color, args[2], e);
+      return Err("illegal move".to_string());
+    }
     info!("new board:\n{:?}", self.game);
     Ok("".to_string())
   }
 
+  fn fixed_handicap(&mut self, args: Vec<&str>) -> Result<String, String> {
+    if args.len() != 2 {
+      return Err("expected: fixed_handicap N".to_string());
+    }
+    let n = try!(parse_num_stones(args[1]));
+    if !self.game.is_empty() {
+      return Err("board not empty".to_string());
+    }
+    let vertices = try!(self.game.fixed_handicap_vertices(n));
+    self.game.place_handicap(&vertices);
+    Ok(format_vertices(&vertices))
+  }
+
+  fn place_free_handicap(&mut self, args: Vec<&str>) -> Result<String, String> {
+    if args.len() != 2 {
+      return Err("expected: place_free_handicap N".to_string());
+    }
+    let n = try!(parse_num_stones(args[1]));
+    if !self.game.is_empty() {
+      return Err("board not empty".to_string());
+    }
+    let vertices = try!(self.game.free_handicap_vertices(n));
+    self.game.place_handicap(&vertices);
+    Ok(format_vertices(&vertices))
+  }
+
+  fn set_free_handicap(&mut self, args: Vec<&str>) -> Result<String, String> {
+    if args.len() < 3 {
+      return Err("expected: set_free_handicap V V ...".to_string());
+    }
+    if !self.game.is_empty() {
+      return Err("board not empty".to_string());
+    }
+    let mut vertices = vec![];
+    for arg in args[1..].iter() {
+      let v = try!(arg.parse::<Vertex>());
+      if !self.game.is_on_board(v) || vertices.contains(&v) ||
+          self.game.stone_at(v) != stone::EMPTY {
+        return Err("bad vertex list".to_string());
+      }
+      vertices.push(v);
+    }
+    self.game.place_handicap(&vertices);
+    Ok("".to_string())
+  }
+
   fn genmove(&mut self, args: Vec<&str>) -> Result<String, String> {
     if args.len() != 2 {
       return Err("expected: genmove C".to_string());
@@ -78,7 +154,8 @@ impl Engine {
     let color = try!(args[1].parse::<Stone>());
     self.game.to_play = color;
     let num_simulations = 10000;
-    let v = self.controller.gen_move(&self.game, num_simulations, &mut self.rng);
+    let v = self.controller.gen_move(&self.game, num_simulations, &mut self.rng)
+      .unwrap_or(PASS);
     self.game.play(color, v);
     let duration = start.to(time::PreciseTime::now());
     info!("generate move in {} ({:.2} kpps)\n{:?}", duration,
@@ -86,6 +163,87 @@ impl Engine {
     Ok(format!("{}", v))
   }
 
+  fn final_status_list(&mut self, args: Vec<&str>) -> Result<String, String> {
+    if args.len() != 2 {
+      return Err("expected: final_status_list alive|dead|seki".to_string());
+    }
+    let wanted = match args[1] {
+      "alive" => StoneStatus::Alive,
+      "dead" => StoneStatus::Dead,
+      "seki" => StoneStatus::Seki,
+      _ => return Err(format!("unknown status '{}'", args[1])),
+    };
+    let num_playouts = 1000;
+    let strings = self.game.final_status(num_playouts, &mut self.rng);
+    // One string per line.
+    Ok(strings.iter().filter(|&&(_, status)| status == wanted)
+      .map(|&(ref stones, _)| format_vertices(stones))
+      .collect::<Vec<String>>().connect("\n"))
+  }
+
+  fn komi(&mut self, args: Vec<&str>) -> Result<String, String> {
+    if args.len() != 2 {
+      return Err("expected: komi K".to_string());
+    }
+    match args[1].parse::<f32>() {
+      Ok(komi) => self.game.komi = komi,
+      Err(_) => return Err(format!("expected float, got '{}'", args[1])),
+    }
+    Ok("".to_string())
+  }
+
+  fn kgs_rules(&mut self, args: Vec<&str>) -> Result<String, String> {
+    if args.len() != 2 {
+      return Err("expected: kgs-rules RULES".to_string());
+    }
+    try!(self.game.set_rules(args[1]));
+    Ok("".to_string())
+  }
+
+  fn final_score(&mut self, _: Vec<&str>) -> Result<String, String> {
+    let num_playouts = 1000;
+    let mut dead = collections::HashSet::new();
+    for (stones, status) in self.game.final_status(num_playouts, &mut self.rng) {
+      if status == StoneStatus::Dead {
+        dead.extend(stones);
+      }
+    }
+    let score = self.game.area_score(&dead) as f32 - self.game.komi;
+    Ok(format_score(score))
+  }
+
+  fn loadsgf(&mut self, args: Vec<&str>) -> Result<String, String> {
+    if args.len() != 2 && args.len() != 3 {
+      return Err("expected: loadsgf FILE [MOVE_NUMBER]".to_string());
+    }
+    // Load the position before move_number is played, or after the last move.
+    let num_moves = if args.len() == 3 {
+      match args[2].parse::<usize>() {
+        Ok(n) if n > 0 => n - 1,
+        _ => return Err(format!("expected move number, got '{}'", args[2])),
+      }
+    } else {
+      usize::max_value()
+    };
+
+    let mut sgf = String::new();
+    let read = fs::File::open(args[1]).and_then(|mut f| f.read_to_string(&mut sgf));
+    if read.is_err() {
+      return Err("cannot load file".to_string());
+    }
+    let (game, game_info) = try!(sgf::load(&sgf, num_moves));
+    self.game = game;
+    self.game_info = game_info;
+    info!("new board:\n{:?}", self.game);
+    Ok("".to_string())
+  }
+
+  // Komi and board size come from the current game, they may have changed
+  // since loadsgf.
+  fn printsgf(&mut self, _: Vec<&str>) -> Result<String, String> {
+    Ok(sgf::save(&self.game, &self.game_info).trim_right().to_string())
+  }
+
   fn move_values(&mut self, _: Vec<&str>) -> Result<String, String> {
     // let num_simulations = 100000;
     // self.controller.gen_move(&self.game, num_simulations, &mut self.rng);
@@ -99,6 +257,22 @@ impl Engine {
     Ok(res)
   }
 
+  // Searches the position and shows how likely each vertex ends up black
+  // (1) or white (-1) in the playouts.
+  fn ownership(&mut self, _: Vec<&str>) -> Result<String, String> {
+    let num_simulations = 10000;
+    self.controller.gen_move(&self.game, num_simulations, &mut self.rng);
+    let ownership = self.controller.ownership();
+    let mut res = "INFLUENCE".to_string();
+    for row in 0 .. self.game.height {
+      for col in 0 .. self.game.width {
+        let v = GoGame::vertex(col as i16, row as i16);
+        res.push_str(&format!(" {} {:.2}", v, ownership[v.as_index()]));
+      }
+    }
+    Ok(res)
+  }
+
   fn quit(&mut self, _: Vec<&str>) -> Result<String, String> {
     self.running = false;
     Ok("".to_string())
@@ -114,6 +288,7 @@ impl Engine {
 
   fn clear_board(&mut self, _: Vec<&str>) -> Result<String, String> {
     self.game.reset();
+    self.game_info = sgf::GameInfo::new();
     Ok("".to_string())
   }
 
@@ -121,14 +296,31 @@ impl Engine {
     if args.len() != 2 {
       return Err("expected: boardsize N".to_string());
     }
-    let n = args[1].parse::<usize>();
-    if n.is_err() {
-      return Err(format!("expected integer, got '{}'", args[1]));
+    let n = try!(parse_size(args[1]));
+    self.new_board(n, n);
+    Ok("".to_string())
+  }
+
+  fn rectangular_boardsize(&mut self, args: Vec<&str>) -> Result<String, String> {
+    if args.len() != 3 {
+      return Err("expected: rectangular_boardsize WIDTH HEIGHT".to_string());
     }
-    self.game = GoGame::new(n.unwrap());
+    let width = try!(parse_size(args[1]));
+    let height = try!(parse_size(args[2]));
+    self.new_board(width, height);
     Ok("".to_string())
   }
 
+  // Empty board of a new size that keeps komi and rules.
+  fn new_board(&mut self, width: usize, height: usize) {
+    let mut game = GoGame::new_rectangular(width, height);
+    game.komi = self.game.komi;
+    game.superko_rule = self.game.superko_rule;
+    game.allow_suicide = self.game.allow_suicide;
+    self.game = game;
+    self.game_info = sgf::GameInfo::new();
+  }
+
   fn list_commands(&mut self, _: Vec<&str>) -> Result<String, String> {
     Ok(self.commands.keys().map(|s| s.clone())
       .collect::<Vec<String>>().connect(" "))
@@ -136,6 +328,40 @@ impl Engine {
 
   fn gogui_analyze_commands(&mut self, _: Vec<&str>) -> Result<String, String> {
     Ok(self.analyze_commands.keys().map(|s| format!("gfx/{}/{}", s, s))
-      .collect::<Vec<String>>().connect(" "))
+      .collect::<Vec<String>>().connect("\n"))
+  }
+}
+
+fn parse_size(arg: &str) -> Result<usize, String> {
+  let n = arg.parse::<usize>();
+  if n.is_err() {
+    return Err(format!("expected integer, got '{}'", arg));
+  }
+  let n = n.unwrap();
+  if n < 1 || n > MAX_SIZE as usize {
+    return Err("unacceptable size".to_string());
+  }
+  return Ok(n);
+}
+
+fn parse_num_stones(arg: &str) -> Result<usize, String> {
+  match arg.parse::<usize>() {
+    Ok(n) => Ok(n),
+    Err(_) => Err(format!("expected integer, got '{}'", arg)),
+  }
+}
+
+fn format_vertices(vertices: &[Vertex]) -> String {
+  return vertices.iter().map(|v| format!("{}", v))
+    .collect::<Vec<String>>().connect(" ");
+}
+
+// Formats a score from black's point of view as B+3.5, W+0.5 or 0 for jigo.
+fn format_score(score: f32) -> String {
+  if score > 0.0 {
+    return format!("B+{}", score);
+  } else if score < 0.0 {
+    return format!("W+{}", -score);
   }
+  return "0".to_string();
 }
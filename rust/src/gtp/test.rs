@@ -0,0 +1,90 @@
+extern crate rand;
+
+use rand::SeedableRng;
+use std::env;
+use std::fs;
+use std::io::Write;
+
+use super::Engine;
+use super::super::go::SuperkoRule;
+
+fn engine() -> Engine {
+  return Engine::new(rand::StdRng::from_seed(&[42]));
+}
+
+#[test]
+fn set_free_handicap_rejects_off_board_vertices() {
+  let mut engine = engine();
+  assert_eq!("? bad vertex list", engine.execute("set_free_handicap A99 C3".to_string()));
+  assert_eq!("? bad vertex list", engine.execute("set_free_handicap C3 A-5".to_string()));
+  assert_eq!("? bad vertex list", engine.execute("set_free_handicap C3 PASS".to_string()));
+  assert!(engine.game.is_empty());
+  assert_eq!("= ", engine.execute("set_free_handicap C3 G7".to_string()));
+}
+
+#[test]
+fn printsgf_keeps_loaded_game_info() {
+  let path = env::temp_dir().join("printsgf_keeps_loaded_game_info.sgf");
+  fs::File::create(&path).and_then(|mut f| f.write_all(
+    b"(;GM[1]SZ[9]KM[6.5]PB[Honinbo Shusaku]PW[Gennan Inseki]RE[B+2]RU[Japanese];B[cc];W[gg])"))
+    .unwrap();
+  let mut engine = engine();
+  assert_eq!("= ", engine.execute(format!("loadsgf {}", path.display())));
+  fs::remove_file(&path).unwrap();
+  assert_eq!("= ", engine.execute("komi 0.5".to_string()));
+
+  let sgf = engine.execute("printsgf".to_string());
+  for property in ["SZ[9]", "KM[0.5]", "PB[Honinbo Shusaku]", "PW[Gennan Inseki]", "RE[B+2]",
+      "RU[Japanese]", ";B[cc]", ";W[gg]"].iter() {
+    assert!(sgf.contains(property), "{} missing in {}", property, sgf);
+  }
+
+  assert_eq!("= ", engine.execute("clear_board".to_string()));
+  assert!(!engine.execute("printsgf".to_string()).contains("PB["));
+}
+
+#[test]
+fn play_rejects_off_board_vertices() {
+  let mut engine = engine();
+  assert_eq!("? illegal move", engine.execute("play b A-5".to_string()));
+  assert_eq!("? illegal move", engine.execute("play w A99".to_string()));
+  assert!(engine.game.is_empty());
+  assert_eq!("= ", engine.execute("play b C3".to_string()));
+  assert_eq!("? illegal move", engine.execute("play w C3".to_string()));
+}
+
+#[test]
+fn kgs_rules() {
+  let mut engine = engine();
+  assert_eq!("= ", engine.execute("kgs-rules new_zealand".to_string()));
+  assert_eq!(SuperkoRule::Situational, engine.game.superko_rule);
+  assert!(engine.game.allow_suicide);
+
+  assert_eq!("= ", engine.execute("kgs-rules aga".to_string()));
+  assert_eq!(SuperkoRule::Situational, engine.game.superko_rule);
+  assert!(!engine.game.allow_suicide);
+
+  for rules in ["chinese", "japanese"].iter() {
+    assert_eq!("= ", engine.execute(format!("kgs-rules {}", rules)));
+    assert_eq!(SuperkoRule::Positional, engine.game.superko_rule);
+    assert!(!engine.game.allow_suicide);
+  }
+  assert_eq!("? unknown rules 'ing'", engine.execute("kgs-rules ing".to_string()));
+}
+
+#[test]
+fn rules_survive_boardsize() {
+  let mut engine = engine();
+  assert_eq!("= ", engine.execute("kgs-rules new_zealand".to_string()));
+  assert_eq!("= ", engine.execute("komi 0.5".to_string()));
+  assert_eq!("= ", engine.execute("rectangular_boardsize 7 9".to_string()));
+  assert_eq!((7, 9), (engine.game.width, engine.game.height));
+  assert_eq!(SuperkoRule::Situational, engine.game.superko_rule);
+  assert!(engine.game.allow_suicide);
+  assert_eq!(0.5, engine.game.komi);
+
+  assert_eq!("= ", engine.execute("boardsize 13".to_string()));
+  assert_eq!((13, 13), (engine.game.width, engine.game.height));
+  assert_eq!(SuperkoRule::Situational, engine.game.superko_rule);
+  assert_eq!(0.5, engine.game.komi);
+}
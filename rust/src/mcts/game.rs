@@ -0,0 +1,176 @@
+extern crate rand;
+
+use std::cmp;
+use std::fmt;
+
+use go::GoGame;
+use go::PosHash;
+use go::Stone;
+use go::Vertex;
+use go::PASS;
+use go::VIRT_LEN;
+use go::stone;
+use go::symmetry;
+use tactics;
+
+// A two-player board game that the Controller can search. The players are
+// stone::BLACK and stone::WHITE.
+pub trait Game: Clone {
+  type Move: Copy + PartialEq + fmt::Debug + fmt::Display;
+
+  fn to_play(&self) -> Stone;
+
+  // Moves the search considers for the player to move, empty once the game is
+  // over.
+  fn legal_moves(&self) -> Vec<Self::Move>;
+
+  // Plays m for the player to move, m must be one of legal_moves.
+  fn play_move(&mut self, m: Self::Move);
+
+  // Takes back the last num_moves moves.
+  fn undo_moves(&mut self, num_moves: usize);
+
+  // All moves played so far, with the player who played them.
+  fn history(&self) -> &[(Stone, Self::Move)];
+
+  // Hash of the position, used as key of the search tree. It must never be
+  // PosHash::None.
+  fn canonical_hash(&self) -> PosHash;
+
+  // Plays random moves until the game is over.
+  fn random_playout(&mut self, rng: &mut rand::StdRng);
+
+  // Winner of a finished game, stone::EMPTY for a draw.
+  fn winner(&self) -> Stone;
+
+  // Moves are mapped to indices below num_move_indices, to track which player
+  // played a move first in a rollout.
+  fn num_move_indices(&self) -> usize;
+  fn move_index(&self, m: Self::Move) -> usize;
+
+  // Player owning the move index at the end of a playout, stone::EMPTY if
+  // neither player owns it.
+  #[allow(unused_variables)]
+  fn owner(&self, index: usize) -> Stone {
+    return stone::EMPTY;
+  }
+
+  // Games whose positions have symmetric variations can share one tree node
+  // between them, by returning the same canonical_hash. The canonical
+  // symmetry identifies the variation of the current position.
+  fn canonical_symmetry(&self) -> usize {
+    return 0;
+  }
+
+  // The move m of the variation with canonical symmetry from_symmetry, in the
+  // variation with canonical symmetry to_symmetry.
+  #[allow(unused_variables)]
+  fn transform_move(&self, m: Self::Move, from_symmetry: usize, to_symmetry: usize) -> Self::Move {
+    return m;
+  }
+
+  // Moves known to be good and bad for the player to move, from reading that
+  // is too slow for playouts. The search starts them with all wins and all
+  // losses respectively.
+  fn tactical_moves(&mut self) -> (Vec<Self::Move>, Vec<Self::Move>) {
+    return (vec![], vec![]);
+  }
+}
+
+impl Game for GoGame {
+  type Move = Vertex;
+
+  fn to_play(&self) -> Stone {
+    return self.to_play;
+  }
+
+  fn legal_moves(&self) -> Vec<Vertex> {
+    return self.candidate_moves(self.to_play);
+  }
+
+  fn play_move(&mut self, vertex: Vertex) {
+    let player = self.to_play;
+    self.play(player, vertex);
+  }
+
+  fn undo_moves(&mut self, num_moves: usize) {
+    self.undo(num_moves);
+  }
+
+  fn history(&self) -> &[(Stone, Vertex)] {
+    return &self.history;
+  }
+
+  fn canonical_hash(&self) -> PosHash {
+    return GoGame::canonical_hash(self);
+  }
+
+  // Random moves until both players pass. Passes are not recorded in the
+  // history.
+  fn random_playout(&mut self, rng: &mut rand::StdRng) {
+    let mut color_to_play = self.to_play;
+    let mut num_consecutive_passes = 0;
+    let mut num_moves = 0;
+    // Random games can in theory go on forever by repeatedly capturing and
+    // refilling the same area.
+    let max_moves = cmp::max(700, 2 * self.width * self.height);
+    // Superko is too expensive to check during random playouts. Undoing the
+    // moves later is fine, they didn't record their positions.
+    let check_superko = self.check_superko;
+    self.check_superko = false;
+
+    while num_consecutive_passes < 2 {
+      num_moves += 1;
+      let v = self.random_move(color_to_play, rng);
+      if v == PASS {
+        num_consecutive_passes += 1;
+      } else {
+        self.play(color_to_play, v);
+        num_consecutive_passes = 0;
+      }
+      color_to_play = color_to_play.opponent();
+      if num_moves > max_moves {
+        warn!("too many moves!");
+        break;
+      }
+    }
+    self.check_superko = check_superko;
+  }
+
+  fn winner(&self) -> Stone {
+    return if self.chinese_score() * 2 > self.double_komi() {
+      stone::BLACK
+    } else {
+      stone::WHITE
+    };
+  }
+
+  fn num_move_indices(&self) -> usize {
+    return VIRT_LEN;
+  }
+
+  fn move_index(&self, vertex: Vertex) -> usize {
+    return vertex.as_index();
+  }
+
+  fn owner(&self, index: usize) -> Stone {
+    let owner = GoGame::owner(self, Vertex(index as i16));
+    return if owner == stone::BORDER { stone::EMPTY } else { owner };
+  }
+
+  fn canonical_symmetry(&self) -> usize {
+    return GoGame::canonical_symmetry(self);
+  }
+
+  // To the canonical variation and back from there.
+  fn transform_move(&self, vertex: Vertex, from_symmetry: usize, to_symmetry: usize) -> Vertex {
+    return vertex.transform(from_symmetry, self.width, self.height)
+      .transform(symmetry::inverse_symmetry(to_symmetry), self.width, self.height);
+  }
+
+  // Ladder captures are good, escaping from a working ladder is useless.
+  fn tactical_moves(&mut self) -> (Vec<Vertex>, Vec<Vertex>) {
+    let player = self.to_play;
+    return tactics::ladder_moves(self, player);
+  }
+}
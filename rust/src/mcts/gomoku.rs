@@ -0,0 +1,148 @@
+extern crate rand;
+
+use rand::Rng;
+use rand::SeedableRng;
+
+use go::PosHash;
+use go::Stone;
+use go::stone;
+use super::Game;
+
+// Directions of the lines through a point, as (x, y) steps.
+const DIRECTIONS: [(isize, isize); 4] = [(1, 0), (0, 1), (1, 1), (1, -1)];
+
+// Gomoku on a small board, to test the search on a game other than Go. Black
+// starts and the first player with num_in_row stones in a horizontal, vertical
+// or diagonal line wins. Moves are the indices of points, row by row.
+#[derive(Clone, Debug)]
+pub struct Gomoku {
+  pub width: usize,
+  pub height: usize,
+  num_in_row: usize,
+  board: Vec<Stone>,
+  history: Vec<(Stone, usize)>,
+  winner: Stone,
+  hash: PosHash,
+  // Zobrist hashes of a black stone on every point, then of a white stone.
+  point_hashes: Vec<PosHash>,
+}
+
+impl Gomoku {
+  pub fn new(width: usize, height: usize, num_in_row: usize) -> Gomoku {
+    let mut rng = rand::StdRng::from_seed(&[width, height]);
+    return Gomoku {
+      width: width,
+      height: height,
+      num_in_row: num_in_row,
+      board: vec![stone::EMPTY; width * height],
+      history: vec![],
+      winner: stone::EMPTY,
+      // Never PosHash::None, the empty board needs a hash too.
+      hash: PosHash::new(rng.gen::<u64>() | 1),
+      point_hashes: (0 .. 2 * width * height).map(|_| PosHash::new(rng.gen())).collect(),
+    };
+  }
+
+  pub fn point(&self, x: usize, y: usize) -> usize {
+    return x + y * self.width;
+  }
+
+  pub fn stone_at(&self, point: usize) -> Stone {
+    return self.board[point];
+  }
+
+  fn point_hash(&self, player: Stone, point: usize) -> PosHash {
+    let offset = if player == stone::BLACK { 0 } else { self.board.len() };
+    return self.point_hashes[offset + point];
+  }
+
+  // Length of the longest line of player's stones through point.
+  fn longest_line(&self, player: Stone, point: usize) -> usize {
+    let (x, y) = ((point % self.width) as isize, (point / self.width) as isize);
+    let mut longest = 0;
+    for &(dx, dy) in DIRECTIONS.iter() {
+      let mut length = 1;
+      for &sign in [-1, 1].iter() {
+        let (mut cx, mut cy) = (x + sign * dx, y + sign * dy);
+        while cx >= 0 && cy >= 0 && (cx as usize) < self.width && (cy as usize) < self.height &&
+            self.board[self.point(cx as usize, cy as usize)] == player {
+          length += 1;
+          cx += sign * dx;
+          cy += sign * dy;
+        }
+      }
+      longest = if length > longest { length } else { longest };
+    }
+    return longest;
+  }
+}
+
+impl Game for Gomoku {
+  type Move = usize;
+
+  fn to_play(&self) -> Stone {
+    return if self.history.len() % 2 == 0 { stone::BLACK } else { stone::WHITE };
+  }
+
+  fn legal_moves(&self) -> Vec<usize> {
+    if self.winner != stone::EMPTY {
+      return vec![];
+    }
+    return (0 .. self.board.len()).filter(|p| self.board[*p] == stone::EMPTY).collect();
+  }
+
+  fn play_move(&mut self, point: usize) {
+    let player = self.to_play();
+    self.board[point] = player;
+    self.hash = self.hash ^ self.point_hash(player, point);
+    self.history.push((player, point));
+    if self.longest_line(player, point) >= self.num_in_row {
+      self.winner = player;
+    }
+  }
+
+  fn undo_moves(&mut self, num_moves: usize) {
+    for _ in 0 .. num_moves {
+      let (player, point) = self.history.pop().unwrap();
+      self.board[point] = stone::EMPTY;
+      self.hash = self.hash ^ self.point_hash(player, point);
+      // The game was still going on before any move.
+      self.winner = stone::EMPTY;
+    }
+  }
+
+  fn history(&self) -> &[(Stone, usize)] {
+    return &self.history;
+  }
+
+  fn canonical_hash(&self) -> PosHash {
+    return self.hash;
+  }
+
+  fn random_playout(&mut self, rng: &mut rand::StdRng) {
+    loop {
+      let moves = self.legal_moves();
+      if moves.is_empty() {
+        return;
+      }
+      let point = moves[rng.gen_range(0, moves.len())];
+      self.play_move(point);
+    }
+  }
+
+  fn winner(&self) -> Stone {
+    return self.winner;
+  }
+
+  fn num_move_indices(&self) -> usize {
+    return self.board.len();
+  }
+
+  fn move_index(&self, point: usize) -> usize {
+    return point;
+  }
+
+  fn owner(&self, point: usize) -> Stone {
+    return self.board[point];
+  }
+}
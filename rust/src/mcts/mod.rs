@@ -1,12 +1,8 @@
 extern crate log;
 extern crate rand;
 
-use go::Vertex;
-use go::PASS;
-use go::GoGame;
 use go::Stone;
 use go::stone;
-use go::VIRT_LEN;
 use rand::Rng;
 use std::sync::atomic::{AtomicUsize, Ordering};
 use std::cmp;
@@ -14,10 +10,13 @@ use std::collections;
 use std::cell;
 use std::ops::Index;
 
-mod zobrist;
-use self::zobrist::BoardHasher;
-use self::zobrist::PosHash;
+use go::PosHash;
 
+pub mod game;
+pub use self::game::Game;
+
+#[cfg(test)]
+mod gomoku;
 #[cfg(test)]
 mod test;
 
@@ -28,9 +27,14 @@ const RAVE_C: f64 = 0.0;
 const RAVE_EQUIV: f64 = 3500.0;
 
 #[derive(Clone)]
-pub struct Node {
+pub struct Node<M> {
+  // Player who made the move leading to this node.
   player: Stone,
-  pub children: Vec<(Vertex, PosHash)>,
+  // Nodes are shared by all symmetric variations of a position. The moves of
+  // the children belong to the variation the node was expanded in, whose
+  // canonical symmetry is stored here.
+  symmetry: usize,
+  pub children: Vec<(M, PosHash)>,
   parents: Vec<PosHash>,
 
   num_plays: u32,
@@ -39,13 +43,13 @@ pub struct Node {
   num_rave_wins: u32,
 }
 
-struct NodeTable {
-  nodes: Vec<(cell::Cell<PosHash>, cell::UnsafeCell<Node>)>,
+struct NodeTable<M> {
+  nodes: Vec<(cell::Cell<PosHash>, cell::UnsafeCell<Node<M>>)>,
   size: AtomicUsize,
 }
 
-impl NodeTable {
-  fn with_capacity(c: usize) -> NodeTable {
+impl<M: Copy> NodeTable<M> {
+  fn with_capacity(c: usize) -> NodeTable<M> {
     let mut table = NodeTable {
       nodes: vec![],
       size: AtomicUsize::new(0),
@@ -59,10 +63,10 @@ impl NodeTable {
     return table;
   }
 
-  fn get_mut(&self, hash: &PosHash) -> &mut Node {
+  fn get_mut(&self, hash: &PosHash) -> &mut Node<M> {
     match self.find(hash) {
       Ok(i) => unsafe {
-        let p_mut: *mut Node = self.nodes[i].1.get();
+        let p_mut: *mut Node<M> = self.nodes[i].1.get();
         &mut *p_mut
       },
       Err(_) => panic!("no entry for {:?}", hash),
@@ -73,7 +77,7 @@ impl NodeTable {
     return self.find(hash).is_ok();
   }
 
-  fn insert(&self, hash: PosHash, node: Node) {
+  fn insert(&self, hash: PosHash, node: Node<M>) {
     if self.size.load(Ordering::SeqCst) + 1 == self.nodes.len() {
       // Always leave at least one empty guard value.
       panic!("NodeTable is already full!");
@@ -82,7 +86,7 @@ impl NodeTable {
     match self.find(&hash) {
       Ok(_) => panic!("{:?} is already in the table", hash),
       Err(i) => unsafe {
-        let p_mut:*mut Node = self.nodes[i].1.get();
+        let p_mut:*mut Node<M> = self.nodes[i].1.get();
         *p_mut = node;
         self.nodes[i].0.set(hash);
       },
@@ -115,71 +119,58 @@ impl NodeTable {
   }
 }
 
-impl Index<PosHash> for NodeTable {
-  type Output = Node;
+impl<M: Copy> Index<PosHash> for NodeTable<M> {
+  type Output = Node<M>;
 
-  fn index<'a>(&'a self, _index: PosHash) -> &'a Node {
+  fn index<'a>(&'a self, _index: PosHash) -> &'a Node<M> {
     return self.get_mut(&_index);
   }
 }
 
-pub struct Controller {
-  pub root: Node,
-  nodes: NodeTable,
-  hasher: BoardHasher,
+pub struct Controller<G: Game> {
+  pub root: Node<G::Move>,
+  nodes: NodeTable<G::Move>,
+  // For every move index, the number of playouts of the last search that
+  // black owned it at the end minus the number that white did.
+  ownership: Vec<i32>,
+  num_playouts: u32,
 }
 
-fn black_wins(game: &mut GoGame, last_move: Stone, rng: &mut rand::StdRng,
-      amaf_color_map: &mut Vec<Stone>) -> bool {
-  let double_komi = 13;
-  let mut color_to_play = last_move;
-  let mut num_consecutive_passes = 0;
-  let mut num_moves = 0;
-
-  while num_consecutive_passes < 2 {
-    // println!("{:?}", game);
-    color_to_play = color_to_play.opponent();
-    num_moves += 1;
-    let v = game.random_move(color_to_play, rng);
-    if v == PASS {
-      num_consecutive_passes += 1;
-    } else {
-      if amaf_color_map[v.as_index()] == stone::EMPTY {
-        amaf_color_map[v.as_index()] = color_to_play;
-      }
-      game.play(color_to_play, v);
-      num_consecutive_passes = 0;
-    }
-    if num_moves > 700 {
-      warn!("too many moves!");
-      return false;
-    }
-  }
-  return game.chinese_score() * 2 > double_komi;
-}
-
-impl Controller {
-  pub fn new() -> Controller {
+impl<G: Game> Controller<G> {
+  pub fn new() -> Controller<G> {
     Controller {
       root: Node::new(stone::WHITE),
       nodes: NodeTable::with_capacity(100000),
-      hasher: BoardHasher::new(),
+      ownership: vec![],
+      num_playouts: 0,
     }
   }
 
-  pub fn gen_move(&mut self, game: &GoGame, num_rollouts: u32, rng: &mut rand::StdRng) -> Vertex {
+  // Average owner of every move index over the playouts of the last gen_move,
+  // from -1 if white always owned it to 1 if black always did.
+  pub fn ownership(&self) -> Vec<f64> {
+    let num_playouts = cmp::max(1, self.num_playouts) as f64;
+    return self.ownership.iter().map(|o| *o as f64 / num_playouts).collect();
+  }
+
+  // Searches for the best move of the player to move in game, None if the
+  // game is over.
+  pub fn gen_move(&mut self, game: &G, num_rollouts: u32, rng: &mut rand::StdRng) -> Option<G::Move> {
     let mut rollout_game = game.clone();
-    if rollout_game.possible_moves(game.to_play).is_empty() {
-      return PASS;
+    self.ownership = vec![0; game.num_move_indices()];
+    self.num_playouts = 0;
+    if rollout_game.legal_moves().is_empty() {
+      return None;
     }
 
-    let root_hash = self.hasher.hash(game);
+    let root_hash = game.canonical_hash();
 
     if self.nodes.contains_key(&root_hash) {
       info!("reusing root with {:?} visits", self.nodes[root_hash].num_plays)
     } else {
       info!("creating a new root");
-      self.nodes.insert(root_hash, Node::new(game.to_play));
+      // Nodes belong to the player who moved into them.
+      self.nodes.insert(root_hash, Node::new(game.to_play().opponent()));
     }
     {
       let mut root = self.nodes.get_mut(&root_hash);
@@ -189,41 +180,43 @@ impl Controller {
     }
 
     for i in 1 .. num_rollouts + 1 {
-      rollout_game.reset();
-      for v in game.history.iter() {
-        rollout_game.play(v.0, v.1);
-      }
       self.run_rollout(i, root_hash, &mut rollout_game, rng);
+      let num_moves = rollout_game.history().len() - game.history().len();
+      rollout_game.undo_moves(num_moves);
     }
 
-    self.print_statistics(root_hash);
-    let (best_v, best_h) = self.nodes[root_hash].best_move(&self.nodes);
-    info!("selected move {:}", best_v);
-    self.print_statistics(best_h);
+    self.print_statistics(root_hash, game);
+    let (best_m, best_h) = self.nodes[root_hash].best_move(&self.nodes);
+    let best_m = self.nodes[root_hash].game_move(best_m, game);
+    info!("selected move {:}", best_m);
+    let mut next = game.clone();
+    next.play_move(best_m);
+    self.print_statistics(best_h, &next);
 
-    return best_v;
+    return Some(best_m);
   }
 
-  fn run_rollout(&mut self, num_sims: u32, root_hash: PosHash, game: &mut GoGame,
+  fn run_rollout(&mut self, num_sims: u32, root_hash: PosHash, game: &mut G,
       rng: &mut rand::StdRng) {
-    // Map to store who played at which vertex first to update node values by AMAF.
-    let mut amaf_color_map = vec![stone::EMPTY; VIRT_LEN];
+    let num_root_moves = game.history().len();
     let mut hash = root_hash;
     let mut node = self.nodes.get_mut(&hash);
+    // Canonical symmetry of the game at every node on the path, to map the
+    // moves of their children to the game.
+    let mut path_symmetries = collections::HashMap::new();
+    path_symmetries.insert(hash, game.canonical_symmetry());
 
     // Run the simulation down the tree until we reach a leaf node.
     while !node.children.is_empty() {
       // Shuffle to break ties, todo(swj): find a faster way to break ties.
       rng.shuffle(&mut node.children);
-      let (vertex, best_hash) = node.best_child(num_sims, &self.nodes);
-      game.play(node.player, vertex);
-
-      if vertex != PASS && amaf_color_map[vertex.as_index()] == stone::EMPTY {
-        amaf_color_map[vertex.as_index()] = node.player;
-      }
+      let (m, best_hash) = node.best_child(num_sims, &self.nodes);
+      let m = node.game_move(m, game);
+      game.play_move(m);
 
       hash = best_hash;
       node = self.nodes.get_mut(&hash);
+      path_symmetries.insert(hash, game.canonical_symmetry());
 
       // Expand nodes with no children that are above the threshold.
       if node.children.is_empty() && node.num_plays > EXPANSION_THRESHOLD {
@@ -232,27 +225,48 @@ impl Controller {
     }
 
     // Run a random rollout till the end of the game.
-    let black_wins = black_wins(game, node.player, rng, &mut amaf_color_map);
+    game.random_playout(rng);
+    let winner = game.winner();
+    self.num_playouts += 1;
+    for i in 0 .. self.ownership.len() {
+      match game.owner(i) {
+        stone::BLACK => self.ownership[i] += 1,
+        stone::WHITE => self.ownership[i] -= 1,
+        _ => {},
+      }
+    }
+
+    // Map to store who played which move first to update node values by AMAF.
+    let mut amaf_color_map = vec![stone::EMPTY; game.num_move_indices()];
+    for &(player, m) in game.history()[num_root_moves ..].iter() {
+      let i = game.move_index(m);
+      if amaf_color_map[i] == stone::EMPTY {
+        amaf_color_map[i] = player;
+      }
+    }
 
     // Propagate the new value up the tree, following all possible parent paths.
     let mut update_nodes = vec![hash];
     while !update_nodes.is_empty() {
-      node = self.nodes.get_mut(&update_nodes.pop().unwrap());
+      let hash = update_nodes.pop().unwrap();
+      node = self.nodes.get_mut(&hash);
       update_nodes.extend(node.parents.clone());
 
-      let wins = if black_wins && node.player == stone::BLACK ||
-          !black_wins && node.player == stone::WHITE {
-        1
-      } else {
-        0
-      };
+      let wins = if winner == node.player { 1 } else { 0 };
       node.num_plays += 1;
       node.num_wins += wins;
 
-      // Update the rave visits of all child nodes.
-      for &(vertex, hash) in node.children.iter() {
+      // Update the rave visits of all child nodes. Only possible for nodes on
+      // the path, the orientation of other parents is unknown and their moves
+      // would be credited for the wrong points.
+      let game_symmetry = match path_symmetries.get(&hash) {
+        Some(s) => *s,
+        None => continue,
+      };
+      for &(m, hash) in node.children.iter() {
         let ref mut child = self.nodes.get_mut(&hash);
-        if amaf_color_map[vertex.as_index()] == child.player {
+        let m = game.transform_move(m, node.symmetry, game_symmetry);
+        if amaf_color_map[game.move_index(m)] == child.player {
           child.num_rave_plays += 1;
           child.num_rave_wins += 1 - wins; // Children are from the other perspective.
         }
@@ -260,22 +274,36 @@ impl Controller {
     }
   }
 
-  fn expand_node(&self, hash: PosHash, node: &mut Node, game: &mut GoGame) {
+  fn expand_node(&self, hash: PosHash, node: &mut Node<G::Move>, game: &mut G) {
     let opponent = node.player.opponent();
-    for v in game.possible_moves(opponent) {
-      game.play(opponent, v);
-      let child_hash = self.hasher.hash(game);
-      game.undo(1);
+    node.symmetry = game.canonical_symmetry();
+    // Too slow for playouts, but cheap enough once per node.
+    let (good_moves, bad_moves) = game.tactical_moves();
+    for m in game.legal_moves() {
+      game.play_move(m);
+      let child_hash = game.canonical_hash();
+      game.undo_moves(1);
+      // Only one of the symmetric moves becomes a child.
+      if node.children.iter().any(|&(_, h)| h == child_hash) {
+        continue;
+      }
       if !self.nodes.contains_key(&child_hash) {
-        self.nodes.insert(child_hash, Node::new(opponent));
+        let child = if good_moves.contains(&m) {
+          Node::with_prior(opponent, NODE_PRIOR)
+        } else if bad_moves.contains(&m) {
+          Node::with_prior(opponent, 0)
+        } else {
+          Node::new(opponent)
+        };
+        self.nodes.insert(child_hash, child);
       }
       // Add this node as parent to its new children.
       self.nodes.get_mut(&child_hash).parents.push(hash);
-      node.children.push((v, child_hash));
+      node.children.push((m, child_hash));
     }
   }
 
-  fn print_statistics(&self, root_hash: PosHash) {
+  fn print_statistics(&self, root_hash: PosHash, game: &G) {
     let ref root = self.nodes[root_hash];
     info!("node hash: {:?}", root_hash);
 
@@ -283,33 +311,36 @@ impl Controller {
     children.sort_by(|a, b| self.nodes[b.1].num_plays.cmp(
         &self.nodes[a.1].num_plays));
     for i in 0 .. cmp::min(10, children.len()) {
-      let (vertex, hash) = children[i];
+      let (m, hash) = children[i];
       let ref child = self.nodes[hash];
-      info!("{:?}: {:} visits {:?}", vertex, child.num_plays, hash);
+      info!("{:?}: {:} visits {:?}", root.game_move(m, game), child.num_plays, hash);
     }
 
-    self.print_pv(root_hash);
+    self.print_pv(root_hash, game);
   }
 
-  fn print_pv(&self, root_hash: PosHash) {
-    let mut hash = root_hash;
-    let mut node = self.nodes.get_mut(&hash);
+  fn print_pv(&self, root_hash: PosHash, game: &G) {
+    let mut game = game.clone();
+    let mut node = self.nodes.get_mut(&root_hash);
     let mut pv = vec![];
 
     while !node.children.is_empty() {
-      let (vertex, hash) = node.best_move(&self.nodes);
+      let (m, hash) = node.best_move(&self.nodes);
+      let m = node.game_move(m, &game);
+      game.play_move(m);
       node = self.nodes.get_mut(&hash);
-      pv.push((vertex, node.num_plays));
+      pv.push((m, node.num_plays));
     }
 
     info!("PV: {:?}", pv);
   }
 }
 
-impl Node {
-  fn new(player: Stone) -> Node {
+impl<M: Copy> Node<M> {
+  fn new(player: Stone) -> Node<M> {
     Node {
       player: player,
+      symmetry: 0,
       children: vec![],
       parents: vec![],
 
@@ -320,7 +351,20 @@ impl Node {
     }
   }
 
-  fn best_move(&self, nodes: &NodeTable) -> (Vertex, PosHash) {
+  // Node whose NODE_PRIOR virtual playouts resulted in num_wins wins.
+  fn with_prior(player: Stone, num_wins: u32) -> Node<M> {
+    let mut node = Node::new(player);
+    node.num_wins = num_wins;
+    return node;
+  }
+
+  // Move of a child in game, which can be any symmetric variation of the
+  // position the node was expanded in.
+  fn game_move<G: Game<Move=M>>(&self, m: M, game: &G) -> M {
+    return game.transform_move(m, self.symmetry, game.canonical_symmetry());
+  }
+
+  fn best_move(&self, nodes: &NodeTable<M>) -> (M, PosHash) {
     let mut max_visits = 0;
     let mut best_child = 0;
     for i in 0 .. self.children.len() {
@@ -333,7 +377,7 @@ impl Node {
     return self.children[best_child];
   }
 
-  fn best_child(&self, num_sims: u32, nodes: &NodeTable) -> (Vertex, PosHash) {
+  fn best_child(&self, num_sims: u32, nodes: &NodeTable<M>) -> (M, PosHash) {
     let mut best_value = -1f64;
     let mut best_child = 0;
     for i in 0 .. self.children.len() {
@@ -1,10 +1,18 @@
+extern crate rand;
+
+use rand::SeedableRng;
 use std::collections;
 
-use super::zobrist::BoardHasher;
-use super::zobrist::PosHash;
+use super::Controller;
+use super::Game;
+use super::NODE_PRIOR;
+use super::gomoku::Gomoku;
+use super::super::go::PosHash;
 use super::super::go::GoGame;
+use super::super::go::Vertex;
+use super::super::go::stone;
 
-fn generate_hashes(depth: usize, hasher: &BoardHasher, game: &mut GoGame, 
+fn generate_hashes(depth: usize, game: &mut GoGame,
 			seen: &mut collections::HashMap<PosHash, GoGame>) {
 	if depth <= 0 {
 		return;
@@ -14,14 +22,14 @@ fn generate_hashes(depth: usize, hasher: &BoardHasher, game: &mut GoGame,
 		let c = game.to_play;
 		game.play(c, v);
 
-		let hash = hasher.hash(&game);
+		let hash = game.hash();
 		if seen.contains_key(&hash) {
 			assert_eq!(game, seen.get(&hash).unwrap());
 		}
 
 		seen.insert(hash, game.clone());
 
-		generate_hashes(depth - 1, hasher, game, seen);
+		generate_hashes(depth - 1, game, seen);
 
 		game.undo(1);
 	}
@@ -29,9 +37,179 @@ fn generate_hashes(depth: usize, hasher: &BoardHasher, game: &mut GoGame,
 
 #[test]
 fn hash_collision() {
-	let hasher = BoardHasher::new();
 	let mut game = GoGame::new(9);
   let mut seen = collections::HashMap::<PosHash, GoGame>::new();
 
-  generate_hashes(2, &hasher, &mut game, &mut seen);
-}
\ No newline at end of file
+  generate_hashes(2, &mut game, &mut seen);
+}
+
+#[test]
+fn ladder_priors() {
+  let mut game = GoGame::new(9);
+  game.setup_stones(stone::BLACK, &["D3".parse::<Vertex>().unwrap(),
+                                    "C4".parse::<Vertex>().unwrap(),
+                                    "C5".parse::<Vertex>().unwrap()]);
+  game.setup_stones(stone::WHITE, &["D4".parse::<Vertex>().unwrap()]);
+  game.to_play = stone::BLACK;
+
+  let mut controller = Controller::new();
+  let mut rng = rand::StdRng::from_seed(&[42]);
+  controller.gen_move(&game, 1, &mut rng);
+
+  let ref root = controller.nodes[game.canonical_hash()];
+  for &(v, hash) in root.children.iter() {
+    let ref child = controller.nodes[hash];
+    if root.game_move(v, &game) == "E4".parse::<Vertex>().unwrap() {
+      assert!(child.num_wins >= NODE_PRIOR);
+    } else {
+      assert!(child.num_wins <= NODE_PRIOR / 2 + 1);
+    }
+  }
+}
+
+#[test]
+fn merge_symmetric_children() {
+  let game = GoGame::new(9);
+  let mut controller = Controller::new();
+  let mut rng = rand::StdRng::from_seed(&[42]);
+  controller.gen_move(&game, 100, &mut rng);
+
+  // One child for each of the 15 points in a triangle covering 1/8 of the board.
+  let ref root = controller.nodes[game.canonical_hash()];
+  assert_eq!(15, root.children.len());
+  let mut hashes = root.children.iter().map(|&(_, h)| h).collect::<Vec<_>>();
+  hashes.sort();
+  hashes.dedup();
+  assert_eq!(15, hashes.len());
+
+  // The same node is found after any of the symmetric moves.
+  let mut corner = game.clone();
+  corner.play(stone::BLACK, "C3".parse::<Vertex>().unwrap());
+  let mut other_corner = game.clone();
+  other_corner.play(stone::BLACK, "G3".parse::<Vertex>().unwrap());
+  assert_eq!(corner.canonical_hash(), other_corner.canonical_hash());
+  assert!(hashes.contains(&corner.canonical_hash()));
+}
+
+#[test]
+fn reuse_symmetric_root() {
+  let mut game = GoGame::new(9);
+  let mut controller = Controller::new();
+  let mut rng = rand::StdRng::from_seed(&[42]);
+  game.play(stone::BLACK, "C3".parse::<Vertex>().unwrap());
+  controller.gen_move(&game, 200, &mut rng);
+
+  // The mirrored position reuses the tree, moves are mapped to its orientation.
+  let mirrored = game.transform(6);
+  assert_eq!(stone::BLACK, mirrored.stone_at("G7".parse::<Vertex>().unwrap()));
+  assert_eq!(game.canonical_hash(), mirrored.canonical_hash());
+  let v = controller.gen_move(&game, 0, &mut rng).unwrap();
+  let mirrored_v = controller.gen_move(&mirrored, 0, &mut rng).unwrap();
+  assert_eq!(v.transform(6, 9, 9), mirrored_v);
+}
+
+#[test]
+fn gomoku_rules() {
+  let mut game = Gomoku::new(5, 5, 4);
+  for x in 0 .. 3 {
+    game.play_move(x);
+    game.play_move(20 + x);
+  }
+  assert_eq!(stone::EMPTY, game.winner());
+  assert_eq!(stone::BLACK, game.to_play());
+  let hash = game.canonical_hash();
+  game.play_move(3);
+  assert_eq!(stone::BLACK, game.winner());
+  assert!(game.legal_moves().is_empty());
+
+  game.undo_moves(1);
+  assert_eq!(stone::EMPTY, game.winner());
+  assert_eq!(hash, game.canonical_hash());
+  assert_eq!(19, game.legal_moves().len());
+}
+
+#[test]
+fn search_gomoku() {
+  // Black threatens to complete a diagonal at the bottom right.
+  let mut game = Gomoku::new(5, 5, 4);
+  for &(x, y) in [(0, 0), (4, 0), (1, 1), (0, 4), (2, 2)].iter() {
+    let p = game.point(x, y);
+    game.play_move(p);
+  }
+  let mut controller = Controller::new();
+  let mut rng = rand::StdRng::from_seed(&[42]);
+  let block = controller.gen_move(&game, 1000, &mut rng).unwrap();
+  assert_eq!(game.point(3, 3), block);
+
+  // After any other move black wins.
+  let elsewhere = game.point(2, 0);
+  game.play_move(elsewhere);
+  let win = controller.gen_move(&game, 1000, &mut rng);
+  assert_eq!(Some(game.point(3, 3)), win);
+  game.play_move(win.unwrap());
+  assert_eq!(None, controller.gen_move(&game, 1000, &mut rng));
+}
+
+#[test]
+fn ownership_from_playouts() {
+  // Black and white groups with three eyes each, fighting over column C.
+  let game = "
+      ABCDE
+    5  # O  5
+    4 ## OO 4
+    3  # O  3
+    2 ## OO 2
+    1  # O  1
+      ABCDE".parse::<GoGame>().unwrap();
+  let mut controller = Controller::new();
+  let mut rng = rand::StdRng::from_seed(&[42]);
+  controller.gen_move(&game, 200, &mut rng);
+
+  let ownership = controller.ownership();
+  let at = |v: &str| ownership[v.parse::<Vertex>().unwrap().as_index()];
+  assert_eq!(1.0, at("A3"));
+  assert_eq!(1.0, at("B1"));
+  assert_eq!(-1.0, at("E5"));
+  assert_eq!(-1.0, at("D2"));
+  assert!(at("C3") > -1.0 && at("C3") < 1.0);
+  assert!(ownership.iter().all(|o| *o >= -1.0 && *o <= 1.0));
+}
+
+#[test]
+fn rave_skips_parents_off_the_path() {
+  // Nobody can win with four in a row on a 3x3 board.
+  let mut game = Gomoku::new(3, 3, 4);
+  let mut controller = Controller::new();
+  let mut rng = rand::StdRng::from_seed(&[42]);
+  controller.gen_move(&game, 2000, &mut rng);
+
+  // Black 0, white 4, black 8 is reached from both orders of black's moves.
+  // The rollouts start after black 0, so black 8 first is off their path.
+  let mut off_path = game.clone();
+  for &p in [8, 4].iter() {
+    off_path.play_move(p);
+  }
+  let off_path = controller.nodes[off_path.canonical_hash()].clone();
+  game.play_move(0);
+  game.play_move(4);
+  let root_hash = game.canonical_hash();
+  let mut transposed = game.clone();
+  transposed.play_move(8);
+  let transposed_hash = transposed.canonical_hash();
+  assert_eq!(2, controller.nodes[transposed_hash].parents.len());
+  assert!(off_path.children.iter().any(|&(_, h)| h == transposed_hash));
+
+  let num_plays = controller.nodes[transposed_hash].num_plays;
+  let rave_plays = |controller: &Controller<Gomoku>| off_path.children.iter()
+    .filter(|&&(_, h)| h != transposed_hash)
+    .map(|&(_, h)| controller.nodes[h].num_rave_plays).collect::<Vec<_>>();
+  let before = rave_plays(&controller);
+  for i in 1 .. 100 {
+    controller.run_rollout(i, root_hash, &mut game, &mut rng);
+    game.undo_moves(game.history().len() - 2);
+  }
+  // The rollouts went through the transposed node, but the other children of
+  // its parent off the path got no credit.
+  assert!(controller.nodes[transposed_hash].num_plays > num_plays);
+  assert_eq!(before, rave_plays(&controller));
+}
@@ -0,0 +1,414 @@
+use go::GoGame;
+use go::Stone;
+use go::Vertex;
+use go::PASS;
+use go::MAX_SIZE;
+use go::stone;
+use std::fmt;
+use std::str;
+
+#[cfg(test)]
+mod test;
+
+// One node of an SGF game tree. The first child continues the main line, any
+// further children are variations.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Node {
+  pub properties: Vec<(String, Vec<String>)>,
+  pub children: Vec<Node>,
+}
+
+impl Node {
+  pub fn new() -> Node {
+    return Node {
+      properties: vec![],
+      children: vec![],
+    };
+  }
+
+  // All values of property id, empty if the node doesn't have it.
+  pub fn values(&self, id: &str) -> &[String] {
+    for &(ref prop, ref values) in self.properties.iter() {
+      if prop == id {
+        return values;
+      }
+    }
+    return &[];
+  }
+
+  // First value of property id.
+  pub fn value(&self, id: &str) -> Option<&str> {
+    return self.values(id).first().map(|v| &v[..]);
+  }
+
+  pub fn add(&mut self, id: &str, value: String) {
+    for &mut (ref prop, ref mut values) in self.properties.iter_mut() {
+      if prop == id {
+        values.push(value);
+        return;
+      }
+    }
+    self.properties.push((id.to_string(), vec![value]));
+  }
+
+  // The nodes of the main line, starting with this one.
+  pub fn main_line(&self) -> Vec<&Node> {
+    let mut nodes = vec![self];
+    let mut cur = self;
+    while let Some(child) = cur.children.first() {
+      nodes.push(child);
+      cur = child;
+    }
+    return nodes;
+  }
+}
+
+// Parses the first game tree of an SGF collection.
+impl str::FromStr for Node {
+  type Err = String;
+
+  fn from_str(s: &str) -> Result<Node, String> {
+    let mut parser = Parser { chars: s.chars().collect(), pos: 0 };
+    parser.skip_whitespace();
+    return parser.game_tree();
+  }
+}
+
+struct Parser {
+  chars: Vec<char>,
+  pos: usize,
+}
+
+impl Parser {
+  fn peek(&self) -> Option<char> {
+    return self.chars.get(self.pos).map(|c| *c);
+  }
+
+  fn skip_whitespace(&mut self) {
+    while self.peek().map_or(false, |c| c.is_whitespace()) {
+      self.pos += 1;
+    }
+  }
+
+  fn expect(&mut self, expected: char) -> Result<(), String> {
+    self.skip_whitespace();
+    if self.peek() != Some(expected) {
+      return Err(format!("expected '{}' at position {}", expected, self.pos));
+    }
+    self.pos += 1;
+    return Ok(());
+  }
+
+  // "(" node+ game_tree* ")", the nodes of the sequence are chained as first
+  // children.
+  fn game_tree(&mut self) -> Result<Node, String> {
+    try!(self.expect('('));
+    let mut sequence = vec![];
+    loop {
+      self.skip_whitespace();
+      if self.peek() != Some(';') {
+        break;
+      }
+      self.pos += 1;
+      sequence.push(try!(self.node()));
+    }
+    if sequence.is_empty() {
+      return Err(format!("empty sequence at position {}", self.pos));
+    }
+
+    let mut variations = vec![];
+    loop {
+      self.skip_whitespace();
+      if self.peek() != Some('(') {
+        break;
+      }
+      variations.push(try!(self.game_tree()));
+    }
+    try!(self.expect(')'));
+
+    let mut tree = sequence.pop().unwrap();
+    tree.children = variations;
+    while let Some(mut parent) = sequence.pop() {
+      parent.children.push(tree);
+      tree = parent;
+    }
+    return Ok(tree);
+  }
+
+  fn node(&mut self) -> Result<Node, String> {
+    let mut node = Node::new();
+    loop {
+      self.skip_whitespace();
+      let mut id = String::new();
+      // Lowercase letters are only allowed in old versions of the format,
+      // e.g. AddBlack for AB.
+      while let Some(c) = self.peek() {
+        if !c.is_alphabetic() {
+          break;
+        }
+        if c.is_uppercase() {
+          id.push(c);
+        }
+        self.pos += 1;
+      }
+      if id.is_empty() {
+        return Ok(node);
+      }
+
+      let mut num_values = 0;
+      loop {
+        self.skip_whitespace();
+        if self.peek() != Some('[') {
+          break;
+        }
+        self.pos += 1;
+        let value = try!(self.value());
+        node.add(&id, value);
+        num_values += 1;
+      }
+      if num_values == 0 {
+        return Err(format!("property {} without value", id));
+      }
+    }
+  }
+
+  // Text up to the closing bracket, with escapes and soft line breaks removed.
+  fn value(&mut self) -> Result<String, String> {
+    let mut value = String::new();
+    loop {
+      match self.peek() {
+        None => return Err("unterminated property value".to_string()),
+        Some(']') => {
+          self.pos += 1;
+          return Ok(value);
+        },
+        Some('\\') => {
+          self.pos += 1;
+          match self.peek() {
+            Some('\n') => {},
+            Some(c) => value.push(c),
+            None => return Err("unterminated property value".to_string()),
+          }
+          self.pos += 1;
+        },
+        Some(c) => {
+          value.push(c);
+          self.pos += 1;
+        },
+      }
+    }
+  }
+}
+
+// Writes the tree as SGF, one node per line.
+impl fmt::Display for Node {
+  fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+    try!(write!(f, "("));
+    let mut cur = self;
+    loop {
+      try!(write!(f, ";"));
+      for &(ref id, ref values) in cur.properties.iter() {
+        try!(write!(f, "{}", id));
+        for v in values.iter() {
+          try!(write!(f, "[{}]", v.replace("\\", "\\\\").replace("]", "\\]")));
+        }
+      }
+      if cur.children.len() != 1 {
+        break;
+      }
+      try!(write!(f, "\n"));
+      cur = &cur.children[0];
+    }
+    for child in cur.children.iter() {
+      try!(write!(f, "\n{}", child));
+    }
+    return write!(f, ")");
+  }
+}
+
+// Game information from the root node that GoGame doesn't keep track of.
+#[derive(Clone, Debug, PartialEq)]
+pub struct GameInfo {
+  pub black: String,
+  pub white: String,
+  // E.g. B+3.5, W+R or 0.
+  pub result: String,
+  pub rules: String,
+  pub handicap: usize,
+}
+
+impl GameInfo {
+  pub fn new() -> GameInfo {
+    return GameInfo {
+      black: String::new(),
+      white: String::new(),
+      result: String::new(),
+      rules: String::new(),
+      handicap: 0,
+    };
+  }
+}
+
+// Sets up the game of the SGF in sgf and plays the first num_moves moves of
+// its main line.
+pub fn load(sgf: &str, num_moves: usize) -> Result<(GoGame, GameInfo), String> {
+  let root = try!(sgf.parse::<Node>());
+  if root.value("GM").map_or(false, |gm| gm != "1") {
+    return Err("not a game of go".to_string());
+  }
+
+  let (width, height) = try!(parse_board_size(root.value("SZ").unwrap_or("19")));
+  let mut game = GoGame::new_rectangular(width, height);
+  let mut info = GameInfo::new();
+  if let Some(komi) = root.value("KM") {
+    game.komi = try!(komi.trim().parse::<f32>().map_err(|_| format!("bad komi '{}'", komi)));
+  }
+  if let Some(handicap) = root.value("HA") {
+    info.handicap = try!(handicap.trim().parse::<usize>()
+      .map_err(|_| format!("bad handicap '{}'", handicap)));
+  }
+  info.black = root.value("PB").unwrap_or("").to_string();
+  info.white = root.value("PW").unwrap_or("").to_string();
+  info.result = root.value("RE").unwrap_or("").to_string();
+  info.rules = root.value("RU").unwrap_or("").to_string();
+  // Same rulesets as GTP kgs-rules, unknown or missing rules keep the defaults.
+  game.set_rules(&info.rules).unwrap_or(());
+
+  // Black's handicap stones are placed before white's first move.
+  if info.handicap > 1 {
+    game.to_play = stone::WHITE;
+  }
+  let mut num_played = 0;
+  for node in root.main_line() {
+    let black = try!(parse_vertices(&game, node.values("AB")));
+    let white = try!(parse_vertices(&game, node.values("AW")));
+    if !black.is_empty() || !white.is_empty() {
+      if num_played > 0 {
+        return Err("setup stones are only supported before the first move".to_string());
+      }
+      game.setup_stones(stone::BLACK, &black);
+      game.setup_stones(stone::WHITE, &white);
+    }
+    if let Some(color) = node.value("PL") {
+      game.to_play = try!(color.parse::<Stone>());
+    }
+
+    for &(color, id) in [(stone::BLACK, "B"), (stone::WHITE, "W")].iter() {
+      if let Some(value) = node.value(id) {
+        if num_played == num_moves {
+          return Ok((game, info));
+        }
+        let v = try!(parse_vertex(&game, value));
+        if let Err(e) = game.try_play(color, v) {
+          return Err(format!("illegal move {}[{}] in move {}: {}", id, value, num_played + 1, e));
+        }
+        num_played += 1;
+      }
+    }
+  }
+  return Ok((game, info));
+}
+
+// SGF of the game, with the starting position as setup stones and all moves
+// played since.
+pub fn save(game: &GoGame, info: &GameInfo) -> String {
+  let mut start = game.clone();
+  start.undo(game.history.len());
+
+  let mut root = Node::new();
+  root.add("FF", "4".to_string());
+  root.add("GM", "1".to_string());
+  if game.width == game.height {
+    root.add("SZ", format!("{}", game.width));
+  } else {
+    root.add("SZ", format!("{}:{}", game.width, game.height));
+  }
+  root.add("KM", format!("{}", game.komi));
+  if info.handicap > 0 {
+    root.add("HA", format!("{}", info.handicap));
+  }
+  let texts = [("RU", &info.rules), ("PB", &info.black), ("PW", &info.white),
+    ("RE", &info.result)];
+  for &(id, value) in texts.iter() {
+    if !value.is_empty() {
+      root.add(id, value.clone());
+    }
+  }
+  for row in 0 .. game.height {
+    for col in 0 .. game.width {
+      let v = GoGame::vertex(col as i16, row as i16);
+      match start.stone_at(v) {
+        stone::BLACK => root.add("AB", format_vertex(game, v)),
+        stone::WHITE => root.add("AW", format_vertex(game, v)),
+        _ => {},
+      }
+    }
+  }
+  if start.to_play == stone::WHITE && info.handicap < 2 {
+    root.add("PL", "W".to_string());
+  }
+
+  // Build the main line from the last move backwards.
+  let mut main_line: Option<Node> = None;
+  for &(color, v) in game.history.iter().rev() {
+    let mut node = Node::new();
+    node.add(if color == stone::BLACK { "B" } else { "W" }, format_vertex(game, v));
+    if let Some(next) = main_line {
+      node.children.push(next);
+    }
+    main_line = Some(node);
+  }
+  if let Some(first) = main_line {
+    root.children.push(first);
+  }
+  return format!("{}\n", root);
+}
+
+// "19" for square boards, "19:13" for rectangular ones.
+fn parse_board_size(value: &str) -> Result<(usize, usize), String> {
+  let sizes = value.split(':').map(|s| s.trim().parse::<usize>()).collect::<Vec<_>>();
+  if sizes.len() > 2 || sizes.iter().any(|s| s.is_err()) {
+    return Err(format!("bad board size '{}'", value));
+  }
+  let width = sizes[0].clone().unwrap();
+  let height = sizes[sizes.len() - 1].clone().unwrap();
+  if width < 1 || height < 1 || width > MAX_SIZE as usize || height > MAX_SIZE as usize {
+    return Err(format!("unsupported board size '{}'", value));
+  }
+  return Ok((width, height));
+}
+
+fn parse_vertices(game: &GoGame, values: &[String]) -> Result<Vec<Vertex>, String> {
+  let mut vertices = vec![];
+  for value in values.iter() {
+    vertices.push(try!(parse_vertex(game, value)));
+  }
+  return Ok(vertices);
+}
+
+// Letters for column and row, starting at "aa" in the upper left corner. Pass
+// is empty, or "tt" on boards up to 19x19.
+fn parse_vertex(game: &GoGame, value: &str) -> Result<Vertex, String> {
+  let chars = value.chars().collect::<Vec<_>>();
+  if chars.is_empty() || value == "tt" && game.width <= 19 && game.height <= 19 {
+    return Ok(PASS);
+  }
+  if chars.len() != 2 || !chars[0].is_lowercase() || !chars[1].is_lowercase() {
+    return Err(format!("bad point '{}'", value));
+  }
+  let x = chars[0] as usize - 'a' as usize;
+  let y = chars[1] as usize - 'a' as usize;
+  if x >= game.width || y >= game.height {
+    return Err(format!("point '{}' is off the board", value));
+  }
+  return Ok(GoGame::vertex(x as i16, (game.height - 1 - y) as i16));
+}
+
+fn format_vertex(game: &GoGame, vertex: Vertex) -> String {
+  if vertex == PASS {
+    return String::new();
+  }
+  let (x, y) = vertex.to_coords();
+  let letters = "abcdefghijklmnopqrstuvwxyz".chars().collect::<Vec<_>>();
+  return format!("{}{}", letters[x as usize], letters[game.height - 1 - y as usize]);
+}
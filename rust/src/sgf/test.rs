@@ -0,0 +1,146 @@
+use super::load;
+use super::save;
+use super::GameInfo;
+use super::Node;
+use super::super::go::GoGame;
+use super::super::go::Vertex;
+use super::super::go::PASS;
+use super::super::go::stone;
+use super::super::go::SuperkoRule;
+
+use rand;
+use rand::SeedableRng;
+
+fn vertex(v: &str) -> Vertex {
+  return v.parse::<Vertex>().unwrap();
+}
+
+#[test]
+fn parse_tree_with_variations() {
+  let root = "(;FF[4]GM[1]SZ[9]C[a \\] bracket and a soft\\\nbreak]
+    ;B[ee];W[cc]
+    (;B[gg])
+    (;B[cg];W[gc]))".parse::<Node>().unwrap();
+  assert_eq!(Some("4"), root.value("FF"));
+  assert_eq!(Some("a ] bracket and a softbreak"), root.value("C"));
+  assert_eq!(None, root.value("KM"));
+
+  let main_line = root.main_line();
+  assert_eq!(4, main_line.len());
+  assert_eq!(Some("gg"), main_line[3].value("B"));
+  let variation = &main_line[2].children[1];
+  assert_eq!(Some("cg"), variation.value("B"));
+  assert_eq!(Some("gc"), variation.children[0].value("W"));
+
+  // Writing and parsing again gives the same tree.
+  assert_eq!(root, format!("{}", root).parse::<Node>().unwrap());
+}
+
+#[test]
+fn parse_property_lists() {
+  let root = "(;AB[aa][bb]\n[cc]AddWhite[dd])".parse::<Node>().unwrap();
+  assert_eq!(3, root.values("AB").len());
+  assert_eq!(vec!["dd".to_string()], root.values("AW"));
+  assert!(root.values("B").is_empty());
+}
+
+#[test]
+fn parse_errors() {
+  assert!("".parse::<Node>().is_err());
+  assert!("()".parse::<Node>().is_err());
+  assert!("(;B[aa]".parse::<Node>().is_err());
+  assert!("(;B[aa)".parse::<Node>().is_err());
+  assert!("(;B)".parse::<Node>().is_err());
+}
+
+#[test]
+fn load_handicap_game() {
+  let sgf = "(;FF[4]GM[1]SZ[9]KM[0.5]HA[2]RU[Japanese]PB[Black]PW[White]RE[W+R]
+    AB[cg][gc];W[ee];B[ge];W[];B[tt])";
+  let (game, info) = load(sgf, usize::max_value()).unwrap();
+  assert_eq!(9, game.width);
+  assert_eq!(0.5, game.komi);
+  assert_eq!(GameInfo {
+    black: "Black".to_string(),
+    white: "White".to_string(),
+    result: "W+R".to_string(),
+    rules: "Japanese".to_string(),
+    handicap: 2,
+  }, info);
+
+  assert_eq!(stone::BLACK, game.stone_at(vertex("C3")));
+  assert_eq!(stone::BLACK, game.stone_at(vertex("G7")));
+  assert_eq!(stone::WHITE, game.stone_at(vertex("E5")));
+  assert_eq!(stone::BLACK, game.stone_at(vertex("G5")));
+  assert_eq!(vec![(stone::WHITE, vertex("E5")), (stone::BLACK, vertex("G5")),
+    (stone::WHITE, PASS), (stone::BLACK, PASS)], game.history);
+
+  // Only the first move.
+  let (game, _) = load(sgf, 1).unwrap();
+  assert_eq!(vec![(stone::WHITE, vertex("E5"))], game.history);
+  assert_eq!(stone::BLACK, game.to_play);
+  let (game, _) = load(sgf, 0).unwrap();
+  assert!(game.history.is_empty());
+  assert_eq!(stone::WHITE, game.to_play);
+}
+
+#[test]
+fn load_rectangular_board() {
+  let (game, _) = load("(;SZ[7:5]RU[NZ];B[ae];W[ga])", usize::max_value()).unwrap();
+  assert_eq!((7, 5), (game.width, game.height));
+  assert!(game.allow_suicide);
+  assert_eq!(stone::BLACK, game.stone_at(GoGame::vertex(0, 0)));
+  assert_eq!(stone::WHITE, game.stone_at(GoGame::vertex(6, 4)));
+}
+
+#[test]
+fn load_rules() {
+  let (game, _) = load("(;SZ[9]RU[AGA];B[aa])", usize::max_value()).unwrap();
+  assert_eq!(SuperkoRule::Situational, game.superko_rule);
+  assert!(!game.allow_suicide);
+  let (game, _) = load("(;SZ[9]RU[Tromp-Taylor])", usize::max_value()).unwrap();
+  assert_eq!(SuperkoRule::Positional, game.superko_rule);
+  assert!(game.allow_suicide);
+  // Unknown rules keep the defaults.
+  let (game, _) = load("(;SZ[9]RU[Ing])", usize::max_value()).unwrap();
+  assert_eq!(SuperkoRule::Positional, game.superko_rule);
+  assert!(!game.allow_suicide);
+}
+
+#[test]
+fn load_errors() {
+  let all = usize::max_value();
+  assert!(load("(;GM[2])", all).is_err());
+  assert!(load("(;SZ[30])", all).is_err());
+  assert!(load("(;SZ[9];B[jj])", all).is_err());
+  assert!(load("(;SZ[9];B[aa];W[aa])", all).is_err());
+  assert!(load("(;SZ[9];B[aa];AW[bb])", all).is_err());
+}
+
+#[test]
+fn save_and_load_games() {
+  let mut rng = rand::StdRng::from_seed(&[42]);
+  for &(width, height) in [(19, 19), (9, 13)].iter() {
+    let mut game = GoGame::new_rectangular(width, height);
+    game.komi = 7.5;
+    let handicap = game.fixed_handicap_vertices(2).unwrap();
+    game.place_handicap(&handicap);
+    for _ in 0 .. 100 {
+      let color = game.to_play;
+      let v = game.random_move(color, &mut rng);
+      game.play(color, v);
+    }
+    let mut info = GameInfo::new();
+    info.handicap = 2;
+    info.black = "a [tricky] name".to_string();
+    info.result = "B+0.5".to_string();
+
+    let sgf = save(&game, &info);
+    let (loaded, loaded_info) = load(&sgf, usize::max_value()).unwrap();
+    assert_eq!(game, loaded);
+    assert_eq!(game.history, loaded.history);
+    assert_eq!(game.to_play, loaded.to_play);
+    assert_eq!(game.komi, loaded.komi);
+    assert_eq!(info, loaded_info);
+  }
+}
@@ -0,0 +1,116 @@
+use go::GoGame;
+use go::Stone;
+use go::Vertex;
+
+#[cfg(test)]
+mod test;
+
+// Ladders that take more moves than this to read are assumed to escape.
+const MAX_LADDER_DEPTH: usize = 200;
+
+// If the string at vertex has exactly two liberties and can be captured in a
+// ladder, returns the atari that starts the ladder.
+// The game is only modified temporarily for reading.
+pub fn ladder_capture(game: &mut GoGame, vertex: Vertex) -> Option<Vertex> {
+  if game.num_liberties(vertex) != 2 {
+    return None;
+  }
+  let check_superko = game.check_superko;
+  game.check_superko = false;
+  let result = capture_with_atari(game, vertex, 0);
+  game.check_superko = check_superko;
+  return result;
+}
+
+// Whether the string at vertex, which must be in atari, is captured in a
+// ladder no matter how its owner tries to escape.
+pub fn ladder_captured(game: &mut GoGame, vertex: Vertex) -> bool {
+  if game.num_liberties(vertex) != 1 {
+    return false;
+  }
+  let check_superko = game.check_superko;
+  game.check_superko = false;
+  let result = captured_in_atari(game, vertex, 0);
+  game.check_superko = check_superko;
+  return result;
+}
+
+// Ladder moves for stone in the current position: ataris that start a working
+// ladder against the opponent, and extensions of our strings in atari that only
+// run into a ladder and are therefore wasted.
+pub fn ladder_moves(game: &mut GoGame, stone: Stone) -> (Vec<Vertex>, Vec<Vertex>) {
+  let mut captures = vec![];
+  let mut useless_escapes = vec![];
+
+  for v in game.strings() {
+    if game.stone_at(v) == stone.opponent() {
+      if let Some(atari) = ladder_capture(game, v) {
+        if !captures.contains(&atari) {
+          captures.push(atari);
+        }
+      }
+    } else if ladder_captured(game, v) {
+      let liberty = game.liberties(v)[0];
+      if !useless_escapes.contains(&liberty) {
+        useless_escapes.push(liberty);
+      }
+    }
+  }
+
+  return (captures, useless_escapes);
+}
+
+// Attacker to move, the string at vertex has two liberties. Returns the first
+// atari that captures the string.
+fn capture_with_atari(game: &mut GoGame, vertex: Vertex, depth: usize) -> Option<Vertex> {
+  if depth > MAX_LADDER_DEPTH {
+    return None;
+  }
+
+  let attacker = game.stone_at(vertex).opponent();
+  for liberty in game.liberties(vertex) {
+    if !game.is_legal(attacker, liberty) {
+      continue;
+    }
+    game.play(attacker, liberty);
+    let captured = game.num_liberties(vertex) == 1 &&
+      captured_in_atari(game, vertex, depth + 1);
+    game.undo(1);
+    if captured {
+      return Some(liberty);
+    }
+  }
+  return None;
+}
+
+// Defender to move, the string at vertex is in atari. It can try to escape by
+// extending or by capturing one of the attacking strings.
+fn captured_in_atari(game: &mut GoGame, vertex: Vertex, depth: usize) -> bool {
+  if depth > MAX_LADDER_DEPTH {
+    return false;
+  }
+
+  let defender = game.stone_at(vertex);
+  let mut escapes = game.liberties(vertex);
+  for enemy in game.adjacent_enemy_strings(vertex) {
+    let liberties = game.liberties(enemy);
+    if liberties.len() == 1 && !escapes.contains(&liberties[0]) {
+      escapes.push(liberties[0]);
+    }
+  }
+
+  for v in escapes {
+    if !game.is_legal(defender, v) {
+      continue;
+    }
+    game.play(defender, v);
+    let num_liberties = game.num_liberties(vertex);
+    let escaped = num_liberties >= 3 ||
+      num_liberties == 2 && capture_with_atari(game, vertex, depth + 1).is_none();
+    game.undo(1);
+    if escaped {
+      return false;
+    }
+  }
+  return true;
+}
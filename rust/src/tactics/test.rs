@@ -0,0 +1,91 @@
+use super::ladder_capture;
+use super::ladder_captured;
+use super::ladder_moves;
+use super::super::go::GoGame;
+use super::super::go::Vertex;
+use super::super::go::stone;
+
+fn vertices(vertices: &str) -> Vec<Vertex> {
+  return vertices.split(" ").filter(|v| !v.is_empty())
+    .map(|v| v.parse::<Vertex>().unwrap()).collect();
+}
+
+fn position(size: usize, black: &str, white: &str) -> GoGame {
+  let mut game = GoGame::new(size);
+  game.setup_stones(stone::BLACK, &vertices(black));
+  game.setup_stones(stone::WHITE, &vertices(white));
+  return game;
+}
+
+fn vertex(v: &str) -> Vertex {
+  return v.parse::<Vertex>().unwrap();
+}
+
+// White D4 is in atari and would run along the diagonal towards the upper
+// right corner.
+const LADDER_BLACK: &'static str = "E4 D3 C4 C5";
+
+#[test]
+fn ladder_to_the_edge() {
+  let mut game = position(19, LADDER_BLACK, "D4");
+  assert!(ladder_captured(&mut game, vertex("D4")));
+  // Reading doesn't change the game.
+  assert_eq!(position(19, LADDER_BLACK, "D4"), game);
+  assert!(game.history.is_empty());
+}
+
+#[test]
+fn ladder_breaker() {
+  let mut game = position(19, LADDER_BLACK, "D4 Q16");
+  assert!(!ladder_captured(&mut game, vertex("D4")));
+}
+
+#[test]
+fn stone_off_the_ladder_path_is_no_breaker() {
+  let mut game = position(19, LADDER_BLACK, "D4 Q4 D16");
+  assert!(ladder_captured(&mut game, vertex("D4")));
+}
+
+#[test]
+fn attacker_breaker_helps_the_ladder() {
+  // A black stone on the path captures even earlier.
+  let mut game = position(19, &format!("{} J9", LADDER_BLACK), "D4 Q16");
+  assert!(ladder_captured(&mut game, vertex("D4")));
+}
+
+#[test]
+fn escape_by_capturing_attacker() {
+  // Black E4 has only one liberty left, white captures it with E5.
+  let mut game = position(19, LADDER_BLACK, "D4 F4 E3");
+  assert!(!ladder_captured(&mut game, vertex("D4")));
+}
+
+#[test]
+fn find_ladder_start() {
+  let mut game = position(19, "D3 C4 C5", "D4");
+  // Only the atari from the right drives white into the ladder, after D5 white
+  // extends to E4 with three liberties.
+  assert_eq!(Some(vertex("E4")), ladder_capture(&mut game, vertex("D4")));
+
+  let mut game = position(19, "D3 C4 C5", "D4 Q16");
+  assert_eq!(None, ladder_capture(&mut game, vertex("D4")));
+}
+
+#[test]
+fn no_ladder_with_three_liberties() {
+  let mut game = position(19, "D3", "D4");
+  assert_eq!(3, game.num_liberties(vertex("D4")));
+  assert_eq!(None, ladder_capture(&mut game, vertex("D4")));
+  assert!(!ladder_captured(&mut game, vertex("D4")));
+}
+
+#[test]
+fn ladder_moves_for_both_sides() {
+  let mut game = position(19, "D3 C4 C5", "D4");
+  assert_eq!((vec![vertex("E4")], vec![]), ladder_moves(&mut game, stone::BLACK));
+  assert_eq!((vec![], vec![]), ladder_moves(&mut game, stone::WHITE));
+
+  // After the atari, extending only loses more stones.
+  game.play(stone::BLACK, vertex("E4"));
+  assert_eq!((vec![], vec![vertex("D5")]), ladder_moves(&mut game, stone::WHITE));
+}